@@ -0,0 +1,353 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    data_dir,
+    stats::{self, CHARS_PER_WORD, CharStats},
+};
+
+/// Books without chapter headings are split into pages of this many words
+pub const WORDS_PER_PAGE: usize = 500;
+/// Longest line still treated as a chapter heading
+const MAX_HEADING_CHARS: usize = 60;
+
+/// Section of a book, `start` is the index of its first word
+#[derive(Debug, Clone)]
+pub struct Chapter {
+    pub title: String,
+    pub start: usize,
+}
+/// Running totals for one chapter
+#[derive(Debug, Default, Clone)]
+pub struct ChapterStats {
+    pub typed: usize,
+    pub correct: usize,
+    pub chars: usize, // correct characters and spaces, for WPM
+    pub seconds: f64,
+}
+impl ChapterStats {
+    /// Average WPM across every round typed in this chapter
+    pub fn wpm(&self) -> f64 {
+        match self.seconds {
+            s if s <= 0.0 => 0.0,
+            s => stats::chars_wpm(self.chars, Duration::from_secs_f64(s)),
+        }
+    }
+}
+/// Imported book with its saved reading position
+#[derive(Debug, Clone)]
+pub struct Book {
+    pub name: String,
+    pub title: String,
+    pub words: Vec<String>,
+    pub chapters: Vec<Chapter>,
+    pub position: usize,
+    pub stats: Vec<ChapterStats>,
+}
+impl Book {
+    /// Splits book text into words and chapters, Project Gutenberg boilerplate is dropped
+    pub fn parse(name: &str, contents: &str) -> Book {
+        let mut title = name.to_string();
+        let mut lines: Vec<&str> = contents.lines().collect();
+        if let Some(start) = lines.iter().position(|l| l.starts_with("*** START OF")) {
+            if let Some(found) = lines[..start].iter().find_map(|l| l.strip_prefix("Title:")) {
+                title = found.trim().to_string();
+            }
+            lines.drain(..=start);
+        }
+        if let Some(end) = lines.iter().position(|l| l.starts_with("*** END OF")) {
+            lines.truncate(end);
+        }
+
+        let mut words = Vec::new();
+        let mut chapters = Vec::new();
+        for line in lines {
+            let line = normalize(line);
+            if is_heading(&line) {
+                chapters.push(Chapter {
+                    title: line.trim().to_string(),
+                    start: words.len(),
+                });
+            }
+            words.extend(line.split_whitespace().map(String::from));
+        }
+        // Text before the first heading is kept as its own section
+        if let Some(first) = chapters.first()
+            && first.start > 0
+        {
+            chapters.insert(
+                0,
+                Chapter {
+                    title: "Opening".to_string(),
+                    start: 0,
+                },
+            );
+        }
+        if chapters.is_empty() {
+            chapters = (0..words.len().max(1))
+                .step_by(WORDS_PER_PAGE)
+                .enumerate()
+                .map(|(i, start)| Chapter {
+                    title: format!("Page {}", i + 1),
+                    start,
+                })
+                .collect();
+        }
+        let stats = vec![ChapterStats::default(); chapters.len()];
+        Book {
+            name: name.to_string(),
+            title,
+            words,
+            chapters,
+            position: 0,
+            stats,
+        }
+    }
+    /// Index of the chapter containing a word
+    pub fn chapter_at(&self, word: usize) -> usize {
+        self.chapters
+            .iter()
+            .rposition(|c| c.start <= word)
+            .unwrap_or(0)
+    }
+    /// Word index where a chapter ends
+    pub fn chapter_end(&self, chapter: usize) -> usize {
+        self.chapters
+            .get(chapter + 1)
+            .map_or(self.words.len(), |c| c.start)
+    }
+    /// Next chunk of words after `start`, empty once the book is finished
+    pub fn chunk(&self, start: usize, count: usize) -> Vec<String> {
+        self.words.iter().skip(start).take(count).cloned().collect()
+    }
+    /// Percentage of the book typed so far
+    pub fn percent_complete(&self) -> f64 {
+        match self.words.len() {
+            0 => 0.0,
+            len => self.position.min(len) as f64 / len as f64 * 100_f64,
+        }
+    }
+    /// Words typed across every session
+    pub fn total_typed(&self) -> usize {
+        self.stats.iter().map(|s| s.typed).sum()
+    }
+    /// Adds a finished round starting at `start`, `words` has one entry per typed word
+    pub fn record_round(&mut self, start: usize, words: &[CharStats], seconds: f64) {
+        if words.is_empty() {
+            return;
+        }
+        let seconds_per_word = seconds / words.len() as f64;
+        for (i, word) in words.iter().enumerate() {
+            let chapter = self.chapter_at(start + i);
+            let stats = &mut self.stats[chapter];
+            stats.typed += 1;
+            stats.correct += usize::from(word.correct_word_chars > 0);
+            stats.chars += word.correct + word.correct_spaces;
+            stats.seconds += seconds_per_word;
+        }
+        self.position = (start + words.len()).min(self.words.len());
+    }
+    /// Progress file kept next to the book text
+    fn progress_path(&self) -> Option<PathBuf> {
+        Some(books_dir()?.join(format!("{}.progress", self.name)))
+    }
+    /// Reads the saved position and chapter totals, unknown lines are ignored
+    fn load_progress(&mut self) {
+        let Some(contents) = self
+            .progress_path()
+            .and_then(|p| fs::read_to_string(p).ok())
+        else {
+            return;
+        };
+        for line in contents.lines() {
+            if let Some(position) = line.strip_prefix("position=") {
+                self.position = position.trim().parse().unwrap_or(0);
+                continue;
+            }
+            // chapter=<index> typed=<n> correct=<n> chars=<n> seconds=<f>
+            let mut chapter = None;
+            let mut stats = ChapterStats::default();
+            let mut chars = None;
+            for field in line.split_whitespace() {
+                match field.split_once('=') {
+                    Some(("chapter", v)) => chapter = v.parse::<usize>().ok(),
+                    Some(("typed", v)) => stats.typed = v.parse().unwrap_or(0),
+                    Some(("correct", v)) => stats.correct = v.parse().unwrap_or(0),
+                    Some(("chars", v)) => chars = v.parse().ok(),
+                    Some(("seconds", v)) => stats.seconds = v.parse().unwrap_or(0.0),
+                    _ => {}
+                }
+            }
+            // Progress saved before character counts estimates them from correct words
+            stats.chars = chars.unwrap_or((stats.correct as f64 * CHARS_PER_WORD) as usize);
+            if let Some(slot) = chapter.and_then(|i| self.stats.get_mut(i)) {
+                *slot = stats;
+            }
+        }
+        // Finished books start again from the top
+        if self.position >= self.words.len() {
+            self.position = 0;
+        }
+    }
+    /// Saves position and chapter totals so the book resumes next session
+    pub fn save_progress(&self) {
+        let Some(path) = self.progress_path() else {
+            return;
+        };
+        let mut lines = vec![format!("position={}", self.position)];
+        for (i, stats) in self.stats.iter().enumerate() {
+            if stats.typed > 0 {
+                lines.push(format!(
+                    "chapter={} typed={} correct={} chars={} seconds={:.1}",
+                    i, stats.typed, stats.correct, stats.chars, stats.seconds
+                ));
+            }
+        }
+        if let Err(e) = fs::write(path, lines.join("\n") + "\n") {
+            eprintln!("Error writing book progress: {}", e);
+        }
+    }
+}
+/// Swaps typographic punctuation for characters found on a keyboard
+fn normalize(line: &str) -> String {
+    line.chars()
+        .map(|c| match c {
+            '\u{2018}' | '\u{2019}' => '\'',
+            '\u{201C}' | '\u{201D}' => '"',
+            '\u{2013}' | '\u{2014}' => '-',
+            '\u{00A0}' => ' ',
+            c => c,
+        })
+        .collect::<String>()
+        .replace('\u{2026}', "...")
+}
+/// Lines like `CHAPTER IV.` or `Chapter 12: The Storm` start a new chapter
+fn is_heading(line: &str) -> bool {
+    let line = line.trim();
+    let mut parts = line.split_whitespace();
+    line.chars().count() <= MAX_HEADING_CHARS
+        && parts
+            .next()
+            .is_some_and(|w| w.eq_ignore_ascii_case("chapter"))
+        && parts.next().is_some_and(|w| {
+            w.trim_end_matches(['.', ':'])
+                .chars()
+                .all(|c| c.is_ascii_digit() || "IVXLCDMivxlcdm".contains(c))
+        })
+}
+/// Directory holding imported books and their progress files
+pub fn books_dir() -> Option<PathBuf> {
+    Some(data_dir()?.join("books"))
+}
+/// Loads every imported book with its saved progress, sorted by file name
+pub fn load_books() -> Vec<Book> {
+    let Some(dir) = books_dir() else {
+        return vec![];
+    };
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return vec![];
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    paths.sort();
+    paths
+        .iter()
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_string_lossy().to_string();
+            let contents = fs::read_to_string(path).ok()?;
+            let mut book = Book::parse(&name, &contents);
+            if book.words.is_empty() {
+                return None;
+            }
+            book.load_progress();
+            Some(book)
+        })
+        .collect()
+}
+/// Copies a text file into the books directory, returning a summary for the user
+pub fn import_book(path: &Path) -> Result<String, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    let name = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .ok_or_else(|| format!("{} is not a file", path.display()))?;
+    let book = Book::parse(&name, &contents);
+    if book.words.is_empty() {
+        return Err(format!("{} contains no words to type", path.display()));
+    }
+    let dir = books_dir().ok_or("could not find the home directory")?;
+    fs::create_dir_all(&dir).map_err(|e| format!("failed to create {}: {}", dir.display(), e))?;
+    let target = dir.join(format!("{}.txt", name));
+    if target.exists() {
+        return Err(format!("a book named '{}' is already imported", name));
+    }
+    fs::write(&target, contents)
+        .map_err(|e| format!("failed to write {}: {}", target.display(), e))?;
+    Ok(format!(
+        "Imported '{}' ({} words, {} chapters) to {}",
+        book.title,
+        book.words.len(),
+        book.chapters.len(),
+        target.display()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_drops_gutenberg_boilerplate() {
+        let contents = "Title: A Short Tale\nLicense text\n*** START OF THE BOOK ***\nOnce upon a time.\n*** END OF THE BOOK ***\nMore license text";
+        let book = Book::parse("tale", contents);
+        assert_eq!(book.name, "tale");
+        assert_eq!(book.title, "A Short Tale");
+        assert_eq!(book.words, vec!["Once", "upon", "a", "time."]);
+    }
+
+    #[test]
+    fn parse_finds_chapters_and_an_opening() {
+        let contents = "Preface words\nCHAPTER I.\nFirst chapter\nChapter 2: The Storm\nrain \u{2014} \u{201C}wind\u{201D}";
+        let book = Book::parse("storm", contents);
+        let chapters: Vec<(&str, usize)> = book
+            .chapters
+            .iter()
+            .map(|c| (c.title.as_str(), c.start))
+            .collect();
+        assert_eq!(
+            chapters,
+            vec![
+                ("Opening", 0),
+                ("CHAPTER I.", 2),
+                ("Chapter 2: The Storm", 6)
+            ]
+        );
+        assert_eq!(book.words[10..], ["rain", "-", "\"wind\""]);
+        assert_eq!(book.stats.len(), 3);
+        assert_eq!(book.chapter_at(7), 2);
+        assert_eq!(book.chapter_end(1), 6);
+    }
+
+    #[test]
+    fn parse_splits_plain_text_into_pages() {
+        let contents = "word ".repeat(WORDS_PER_PAGE + 1);
+        let book = Book::parse("plain", &contents);
+        assert_eq!(book.title, "plain");
+        let starts: Vec<usize> = book.chapters.iter().map(|c| c.start).collect();
+        assert_eq!(starts, vec![0, WORDS_PER_PAGE]);
+        assert_eq!(book.chapters[1].title, "Page 2");
+    }
+
+    #[test]
+    fn chapter_headings_need_a_number() {
+        assert!(is_heading("Chapter XII"));
+        assert!(!is_heading("Chapter and verse were quoted"));
+        assert!(!is_heading("The chapter 1"));
+    }
+}
@@ -0,0 +1,137 @@
+use crate::MAX_ROUND_SECONDS;
+
+/// Printed for `--help` and after invalid arguments
+pub const USAGE: &str =
+    "Usage: TerminalType [--daily] [--time <seconds>] [--seed <number>] [--text <file>] [--import-book <file>]
+
+Options:
+  --daily          Play today's daily challenge, the same test for everyone today
+  --time <seconds> Play timed rounds of any length instead of the saved round time
+  --seed <number>  Generate the same text every round, share it to race the same words
+  --text <file>    Type through a text file in order, use - to read from stdin
+  --import-book <file>
+                   Copy a long text into the books directory for book mode
+  -h, --help       Show this message";
+
+/// Options passed on the command line, these override the saved config
+#[derive(Debug, Default)]
+pub struct CliArgs {
+    pub time: Option<u64>,
+    pub seed: Option<u64>,
+    pub text: Option<String>,
+    pub import_book: Option<String>,
+    pub daily: bool,
+    pub help: bool,
+}
+impl CliArgs {
+    /// Parses arguments, excluding the program name
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<CliArgs, String> {
+        let mut cli = CliArgs::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => cli.help = true,
+                "--daily" => cli.daily = true,
+                "--time" => {
+                    let value = args.next().ok_or("--time needs a number of seconds")?;
+                    cli.time = Some(parse_time(&value)?);
+                }
+                "--seed" => {
+                    let value = args.next().ok_or("--seed needs a number")?;
+                    cli.seed = Some(parse_seed(&value)?);
+                }
+                "--text" => {
+                    cli.text = Some(args.next().ok_or("--text needs a file path or -")?);
+                }
+                "--import-book" => {
+                    cli.import_book = Some(args.next().ok_or("--import-book needs a file path")?);
+                }
+                _ => {
+                    if let Some(value) = arg.strip_prefix("--time=") {
+                        cli.time = Some(parse_time(value)?);
+                    } else if let Some(value) = arg.strip_prefix("--seed=") {
+                        cli.seed = Some(parse_seed(value)?);
+                    } else if let Some(value) = arg.strip_prefix("--text=") {
+                        cli.text = Some(value.to_string());
+                    } else {
+                        return Err(format!("unknown argument '{}'", arg));
+                    }
+                }
+            }
+        }
+        if cli.daily && cli.text.is_some() {
+            return Err("--daily can not be combined with --text".to_string());
+        }
+        Ok(cli)
+    }
+}
+/// Round times are whole seconds, from 1 up to an hour
+fn parse_time(value: &str) -> Result<u64, String> {
+    match value.parse::<u64>() {
+        Ok(seconds) if (1..=MAX_ROUND_SECONDS).contains(&seconds) => Ok(seconds),
+        _ => Err(format!(
+            "invalid time '{}', expected seconds between 1 and {}",
+            value, MAX_ROUND_SECONDS
+        )),
+    }
+}
+/// Seeds are plain non negative numbers so they are easy to share
+fn parse_seed(value: &str) -> Result<u64, String> {
+    value
+        .parse::<u64>()
+        .map_err(|_| format!("invalid seed '{}', expected a whole number", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<CliArgs, String> {
+        CliArgs::parse(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn seed_is_read_in_both_forms() {
+        assert_eq!(parse(&["--seed", "1234"]).unwrap().seed, Some(1234));
+        assert_eq!(parse(&["--seed=42"]).unwrap().seed, Some(42));
+        assert_eq!(parse(&[]).unwrap().seed, None);
+    }
+
+    #[test]
+    fn bad_seeds_are_rejected() {
+        assert_eq!(parse(&["--seed"]).unwrap_err(), "--seed needs a number");
+        assert_eq!(
+            parse(&["--seed", "-1"]).unwrap_err(),
+            "invalid seed '-1', expected a whole number"
+        );
+        assert!(parse(&["--seed=abc"]).is_err());
+    }
+
+    #[test]
+    fn text_takes_a_path_or_stdin() {
+        assert_eq!(
+            parse(&["--text", "notes.txt"]).unwrap().text.as_deref(),
+            Some("notes.txt")
+        );
+        assert_eq!(parse(&["--text=-"]).unwrap().text.as_deref(), Some("-"));
+        assert_eq!(
+            parse(&["--text"]).unwrap_err(),
+            "--text needs a file path or -"
+        );
+    }
+
+    #[test]
+    fn text_can_not_be_a_daily() {
+        assert_eq!(
+            parse(&["--daily", "--text", "notes.txt"]).unwrap_err(),
+            "--daily can not be combined with --text"
+        );
+    }
+
+    #[test]
+    fn unknown_arguments_are_rejected() {
+        assert_eq!(
+            parse(&["--txt", "notes.txt"]).unwrap_err(),
+            "unknown argument '--txt'"
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use rand::{Rng, seq::IndexedRandom};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::data_dir;
+
+/// Spaces inserted for a tab, source tabs are expanded to match
+pub const TAB_WIDTH: usize = 4;
+/// Lines taken from a local file per snippet
+const LOCAL_SNIPPET_LINES: usize = 12;
+/// Files larger than this are skipped when scanning the code directory
+const MAX_LOCAL_FILE_BYTES: u64 = 512 * 1024;
+/// Extensions treated as source files in the code directory
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "rs", "py", "go", "js", "ts", "c", "h", "cpp", "hpp", "java", "rb", "sh", "lua", "zig", "kt",
+    "swift", "cs",
+];
+
+/// Used for user code language config
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum CodeLanguage {
+    #[default]
+    Any,
+    Rust,
+    Python,
+    Go,
+    Local,
+}
+impl CodeLanguage {
+    /// Maps config index to code language
+    pub fn from_index(index: usize) -> CodeLanguage {
+        match index {
+            1 => CodeLanguage::Rust,
+            2 => CodeLanguage::Python,
+            3 => CodeLanguage::Go,
+            4 => CodeLanguage::Local,
+            _ => CodeLanguage::Any,
+        }
+    }
+}
+/// Bundled code sample
+#[derive(Debug)]
+pub struct Snippet {
+    pub language: CodeLanguage,
+    pub code: &'static str,
+}
+/// Characters counted towards symbol accuracy
+pub fn is_symbol(c: char) -> bool {
+    c.is_ascii_punctuation()
+}
+/// Directory scanned for local source files
+pub fn code_dir() -> Option<PathBuf> {
+    Some(data_dir()?.join("code"))
+}
+/// Returns the lines of a random snippet, indentation kept and blank lines dropped
+pub fn snippet_lines<R: Rng>(language: CodeLanguage, rng: &mut R) -> Vec<String> {
+    if language == CodeLanguage::Local
+        && let Some(lines) = local_snippet(rng)
+    {
+        return lines;
+    }
+    let candidates: Vec<&Snippet> = SNIPPETS
+        .iter()
+        .filter(|s| {
+            matches!(language, CodeLanguage::Any | CodeLanguage::Local) || s.language == language
+        })
+        .collect();
+    let snippet = candidates
+        .choose(rng)
+        .expect("bundled snippets for every language");
+    clean_lines(snippet.code.lines())
+}
+/// Expands tabs, trims trailing whitespace and drops blank lines
+fn clean_lines<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<String> {
+    lines
+        .map(|line| {
+            line.replace('\t', &" ".repeat(TAB_WIDTH))
+                .trim_end()
+                .to_string()
+        })
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+/// Picks a window of lines from a random file in the code directory
+fn local_snippet<R: Rng>(rng: &mut R) -> Option<Vec<String>> {
+    let mut files = Vec::new();
+    collect_source_files(&code_dir()?, &mut files, 0);
+    // Directory order varies, sort so seeded rounds pick the same file
+    files.sort();
+    let path = files.choose(rng)?;
+    let contents = fs::read_to_string(path).ok()?;
+    let lines = clean_lines(contents.lines());
+    if lines.is_empty() {
+        return None;
+    }
+    let start = rng.random_range(0..lines.len().saturating_sub(LOCAL_SNIPPET_LINES).max(1));
+    Some(
+        lines
+            .into_iter()
+            .skip(start)
+            .take(LOCAL_SNIPPET_LINES)
+            .collect(),
+    )
+}
+/// Recursively finds source files, skipping hidden directories
+fn collect_source_files(dir: &Path, files: &mut Vec<PathBuf>, depth: usize) {
+    if depth > 4 {
+        return;
+    }
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let hidden = path
+            .file_name()
+            .is_some_and(|n| n.to_string_lossy().starts_with('.'));
+        if hidden {
+            continue;
+        }
+        if path.is_dir() {
+            collect_source_files(&path, files, depth + 1);
+        } else if path
+            .extension()
+            .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext.to_string_lossy().as_ref()))
+            && entry
+                .metadata()
+                .is_ok_and(|m| m.len() <= MAX_LOCAL_FILE_BYTES)
+        {
+            files.push(path);
+        }
+    }
+}
+/// Bundled snippets used when no local files are available
+pub const SNIPPETS: &[Snippet] = &[
+    Snippet {
+        language: CodeLanguage::Rust,
+        code: r#"fn main() {
+    let words = vec!["alpha", "beta", "gamma"];
+    for (i, word) in words.iter().enumerate() {
+        println!("{}: {}", i, word);
+    }
+}"#,
+    },
+    Snippet {
+        language: CodeLanguage::Rust,
+        code: r#"#[derive(Debug, Clone)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+impl Point {
+    pub fn distance(&self, other: &Point) -> f64 {
+        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2)).sqrt()
+    }
+}"#,
+    },
+    Snippet {
+        language: CodeLanguage::Rust,
+        code: r#"fn read_config(path: &Path) -> io::Result<HashMap<String, String>> {
+    let contents = fs::read_to_string(path)?;
+    let mut map = HashMap::new();
+    for line in contents.lines() {
+        if let Some((key, value)) = line.split_once('=') {
+            map.insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+    Ok(map)
+}"#,
+    },
+    Snippet {
+        language: CodeLanguage::Rust,
+        code: r#"match event::read()? {
+    Event::Key(key) if key.kind == KeyEventKind::Press => {
+        self.handle_key(key);
+    }
+    Event::Resize(width, height) => self.resize(width, height),
+    _ => {}
+}"#,
+    },
+    Snippet {
+        language: CodeLanguage::Python,
+        code: r#"def fibonacci(n):
+    a, b = 0, 1
+    for _ in range(n):
+        yield a
+        a, b = b, a + b
+
+print(list(fibonacci(10)))"#,
+    },
+    Snippet {
+        language: CodeLanguage::Python,
+        code: r#"class Stack:
+    def __init__(self):
+        self.items = []
+
+    def push(self, item):
+        self.items.append(item)
+
+    def pop(self):
+        if not self.items:
+            raise IndexError("pop from empty stack")
+        return self.items.pop()"#,
+    },
+    Snippet {
+        language: CodeLanguage::Python,
+        code: r#"with open("scores.csv") as f:
+    rows = [line.strip().split(",") for line in f]
+best = max(rows, key=lambda row: int(row[1]))
+print(f"{best[0]} scored {best[1]} wpm")"#,
+    },
+    Snippet {
+        language: CodeLanguage::Python,
+        code: r#"def word_counts(text: str) -> dict[str, int]:
+    counts = {}
+    for word in text.lower().split():
+        counts[word] = counts.get(word, 0) + 1
+    return dict(sorted(counts.items(), key=lambda kv: -kv[1]))"#,
+    },
+    Snippet {
+        language: CodeLanguage::Go,
+        code: r#"package main
+
+import "fmt"
+
+func main() {
+    for i := 1; i <= 15; i++ {
+        switch {
+        case i%15 == 0:
+            fmt.Println("FizzBuzz")
+        case i%3 == 0:
+            fmt.Println("Fizz")
+        default:
+            fmt.Println(i)
+        }
+    }
+}"#,
+    },
+    Snippet {
+        language: CodeLanguage::Go,
+        code: r#"type Server struct {
+    addr    string
+    handler http.Handler
+}
+
+func (s *Server) Start() error {
+    log.Printf("listening on %s", s.addr)
+    return http.ListenAndServe(s.addr, s.handler)
+}"#,
+    },
+    Snippet {
+        language: CodeLanguage::Go,
+        code: r#"func worker(id int, jobs <-chan int, results chan<- int) {
+    for j := range jobs {
+        results <- j * 2
+    }
+}"#,
+    },
+    Snippet {
+        language: CodeLanguage::Go,
+        code: r#"data, err := os.ReadFile(path)
+if err != nil {
+    return nil, fmt.Errorf("read %s: %w", path, err)
+}
+var cfg Config
+if err := json.Unmarshal(data, &cfg); err != nil {
+    return nil, err
+}
+return &cfg, nil"#,
+    },
+];
@@ -0,0 +1,150 @@
+use chrono::{Datelike, Days, Local, NaiveDate};
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::PathBuf,
+};
+
+use crate::{ConfigIndex, data_dir};
+
+/// Official result of a day's first attempt
+#[derive(Debug, Clone)]
+pub struct DailyResult {
+    pub date: NaiveDate,
+    pub wpm: usize,
+    pub accuracy: f64,
+}
+/// Local date the daily challenge is picked for
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+/// Same seed for everyone on the same date, e.g. 20250314
+pub fn seed_for(date: NaiveDate) -> u64 {
+    date.year() as u64 * 10_000 + date.month() as u64 * 100 + date.day() as u64
+}
+/// Fixed settings so every daily test is the same whatever the saved config is
+pub fn config_for(date: NaiveDate) -> ConfigIndex {
+    ConfigIndex {
+        round_time_index: 1, // 1 minute
+        text_theme_index: 5, // English 1k
+        seed: Some(seed_for(date)),
+        ..ConfigIndex::default()
+    }
+}
+/// File holding one official result per day
+fn daily_path() -> Option<PathBuf> {
+    Some(data_dir()?.join("daily.txt"))
+}
+/// Reads official results oldest first, malformed lines are skipped
+pub fn load_daily() -> Vec<DailyResult> {
+    let Some(contents) = daily_path().and_then(|p| fs::read_to_string(p).ok()) else {
+        return vec![];
+    };
+    contents
+        .lines()
+        .filter_map(|line| {
+            // <yyyy-mm-dd> <wpm> <accuracy>
+            let mut parts = line.split_whitespace();
+            Some(DailyResult {
+                date: NaiveDate::parse_from_str(parts.next()?, "%Y-%m-%d").ok()?,
+                wpm: parts.next()?.parse().ok()?,
+                accuracy: parts.next()?.parse().ok()?,
+            })
+        })
+        .collect()
+}
+/// Saves the first attempt of a day, returns false when that day already has one
+pub fn record_daily(result: &DailyResult) -> bool {
+    if load_daily().iter().any(|r| r.date == result.date) {
+        return false;
+    }
+    let Some(path) = daily_path() else {
+        return false;
+    };
+    if let Some(dir) = path.parent()
+        && let Err(e) = fs::create_dir_all(dir)
+    {
+        eprintln!("Failed to create directory for daily file: {}", e);
+        return false;
+    }
+    let line = format!(
+        "{} {} {:.1}",
+        result.date.format("%Y-%m-%d"),
+        result.wpm,
+        result.accuracy
+    );
+    let written = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{}", line));
+    if let Err(e) = written {
+        eprintln!("Error writing to daily.txt: {}", e);
+        return false;
+    }
+    true
+}
+/// Days in a row with an official result, still counting when today is not played yet
+pub fn streak(results: &[DailyResult], today: NaiveDate) -> usize {
+    let played = |date: NaiveDate| results.iter().any(|r| r.date == date);
+    let mut day = match played(today) {
+        true => today,
+        false => match today.checked_sub_days(Days::new(1)) {
+            Some(yesterday) => yesterday,
+            None => return 0,
+        },
+    };
+    let mut count = 0;
+    while played(day) {
+        count += 1;
+        match day.checked_sub_days(Days::new(1)) {
+            Some(previous) => day = previous,
+            None => break,
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn played(dates: &[&str]) -> Vec<DailyResult> {
+        dates
+            .iter()
+            .map(|d| DailyResult {
+                date: date(d),
+                wpm: 60,
+                accuracy: 95.0,
+            })
+            .collect()
+    }
+
+    fn date(d: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(d, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn streak_counts_days_in_a_row() {
+        let results = played(&["2025-03-10", "2025-03-12", "2025-03-13", "2025-03-14"]);
+        assert_eq!(streak(&results, date("2025-03-14")), 3);
+    }
+
+    #[test]
+    fn streak_holds_until_today_is_played() {
+        let results = played(&["2025-02-28", "2025-03-01"]);
+        assert_eq!(streak(&results, date("2025-03-02")), 2);
+    }
+
+    #[test]
+    fn streak_breaks_after_a_missed_day() {
+        let results = played(&["2025-03-10", "2025-03-11"]);
+        assert_eq!(streak(&results, date("2025-03-13")), 0);
+        assert_eq!(streak(&[], date("2025-03-13")), 0);
+    }
+
+    #[test]
+    fn seed_is_the_date() {
+        assert_eq!(seed_for(date("2025-03-14")), 20250314);
+    }
+}
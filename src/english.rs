@@ -0,0 +1,73 @@
+use rand::{
+    Rng,
+    distr::{Distribution, weighted::WeightedIndex},
+};
+
+/// Bundled English words ordered from most to least frequent
+const ENGLISH_WORDS: &str = include_str!("data/english_10k.txt");
+
+/// Size of each English frequency theme
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EnglishSize {
+    Top200,
+    Top1k,
+    Top10k,
+}
+impl EnglishSize {
+    /// Number of ranked words included
+    fn count(&self) -> usize {
+        match self {
+            EnglishSize::Top200 => 200,
+            EnglishSize::Top1k => 1000,
+            EnglishSize::Top10k => 10000,
+        }
+    }
+}
+/// Most frequent words for the chosen size, in rank order
+pub fn ranked_words(size: EnglishSize) -> Vec<&'static str> {
+    ENGLISH_WORDS.lines().take(size.count()).collect()
+}
+/// Samples word indexes with Zipf weights, rank r is drawn in proportion to 1 / r
+pub struct ZipfSampler {
+    index: WeightedIndex<f64>,
+}
+impl ZipfSampler {
+    pub fn new(len: usize) -> ZipfSampler {
+        let weights = (1..=len.max(1)).map(|rank| 1.0 / rank as f64);
+        ZipfSampler {
+            index: WeightedIndex::new(weights).expect("zipf weights are positive"),
+        }
+    }
+    /// Index of the next word, lower ranks are far more likely
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> usize {
+        self.index.sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+    use std::collections::HashSet;
+
+    #[test]
+    fn ranked_words_are_unique_and_sized() {
+        let words = ranked_words(EnglishSize::Top10k);
+        assert_eq!(words.len(), 10000);
+        assert_eq!(words.iter().collect::<HashSet<_>>().len(), words.len());
+        assert!(words.iter().all(|w| !w.is_empty() && !w.contains(' ')));
+        assert_eq!(ranked_words(EnglishSize::Top200).len(), 200);
+        assert_eq!(ranked_words(EnglishSize::Top1k)[..200], words[..200]);
+    }
+
+    #[test]
+    fn zipf_favours_low_ranks() {
+        let sampler = ZipfSampler::new(100);
+        let mut rng = StdRng::seed_from_u64(7);
+        let draws: Vec<usize> = (0..10_000).map(|_| sampler.sample(&mut rng)).collect();
+        assert!(draws.iter().all(|&i| i < 100));
+        let first = draws.iter().filter(|&&i| i == 0).count();
+        let last = draws.iter().filter(|&&i| i == 99).count();
+        assert!(first > last * 10);
+    }
+}
@@ -0,0 +1,175 @@
+use std::{fs, path::PathBuf};
+
+use crate::data_dir;
+
+/// Words per split when comparing a round against its ghost
+pub const GHOST_SPLIT_WORDS: usize = 10;
+
+/// Cursor position after a key press, `ms` is time since the round started
+#[derive(Debug, Clone, Copy)]
+pub struct Keystroke {
+    pub ms: u64,
+    pub word: usize,
+    pub char: usize,
+}
+/// Best recorded run for a leaderboard category, replayed over the same seeded words
+#[derive(Debug, Clone)]
+pub struct Ghost {
+    pub seed: u64,
+    pub wpm: usize,
+    pub keys: Vec<Keystroke>,
+}
+impl Ghost {
+    /// Reads the saved run for a category, None when there is none
+    pub fn load(category: &str) -> Option<Ghost> {
+        let contents = fs::read_to_string(ghost_path(category)?).ok()?;
+        let mut seed = None;
+        let mut wpm = 0;
+        let mut keys = Vec::new();
+        for line in contents.lines() {
+            if let Some(value) = line.strip_prefix("seed=") {
+                seed = value.trim().parse().ok();
+            } else if let Some(value) = line.strip_prefix("wpm=") {
+                wpm = value.trim().parse().unwrap_or(0);
+            } else {
+                // <ms> <word> <char>
+                let mut parts = line.split_whitespace().map(|p| p.parse().ok());
+                if let (Some(Some(ms)), Some(Some(word)), Some(Some(char))) =
+                    (parts.next(), parts.next(), parts.next())
+                {
+                    keys.push(Keystroke {
+                        ms,
+                        word: word as usize,
+                        char: char as usize,
+                    });
+                }
+            }
+        }
+        Some(Ghost {
+            seed: seed?,
+            wpm,
+            keys,
+        })
+    }
+    /// Saves this run as the ghost for a category
+    pub fn save(&self, category: &str) {
+        let Some(path) = ghost_path(category) else {
+            return;
+        };
+        if let Some(dir) = path.parent()
+            && let Err(e) = fs::create_dir_all(dir)
+        {
+            eprintln!("Failed to create directory for ghosts: {}", e);
+            return;
+        }
+        let mut lines = vec![format!("seed={}", self.seed), format!("wpm={}", self.wpm)];
+        lines.extend(
+            self.keys
+                .iter()
+                .map(|k| format!("{} {} {}", k.ms, k.word, k.char)),
+        );
+        if let Err(e) = fs::write(path, lines.join("\n") + "\n") {
+            eprintln!("Error writing ghost run: {}", e);
+        }
+    }
+    /// Where the ghost cursor was `ms` into the round
+    pub fn position_at(&self, ms: u64) -> (usize, usize) {
+        self.keys
+            .iter()
+            .take_while(|k| k.ms <= ms)
+            .last()
+            .map_or((0, 0), |k| (k.word, k.char))
+    }
+}
+/// Time each word was finished at, in order
+pub fn word_times(keys: &[Keystroke]) -> Vec<u64> {
+    let mut times = Vec::new();
+    for key in keys {
+        while times.len() < key.word {
+            times.push(key.ms);
+        }
+    }
+    times
+}
+/// Seconds gained on the ghost in each split, negative when time was lost
+pub fn splits(keys: &[Keystroke], ghost: &Ghost) -> Vec<f64> {
+    let ours = word_times(keys);
+    let theirs = word_times(&ghost.keys);
+    let words = ours.len().min(theirs.len());
+    let mut splits = Vec::new();
+    let mut start = 0;
+    while start < words {
+        let end = (start + GHOST_SPLIT_WORDS).min(words) - 1;
+        let before = |times: &[u64]| match start {
+            0 => 0,
+            i => times[i - 1],
+        };
+        let our_split = ours[end] - before(&ours);
+        let their_split = theirs[end] - before(&theirs);
+        splits.push((their_split as f64 - our_split as f64) / 1000.0);
+        start = end + 1;
+    }
+    splits
+}
+/// One ghost file per leaderboard category
+fn ghost_path(category: &str) -> Option<PathBuf> {
+    Some(data_dir()?.join("ghosts").join(format!("{}.txt", category)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Keystrokes that finish word `i` at `times[i]`
+    fn finished_at(times: &[u64]) -> Vec<Keystroke> {
+        times
+            .iter()
+            .enumerate()
+            .map(|(i, &ms)| Keystroke {
+                ms,
+                word: i + 1,
+                char: 0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn word_times_fill_skipped_words() {
+        let keys = [
+            Keystroke {
+                ms: 100,
+                word: 0,
+                char: 1,
+            },
+            Keystroke {
+                ms: 400,
+                word: 2,
+                char: 0,
+            },
+        ];
+        assert_eq!(word_times(&keys), vec![400, 400]);
+    }
+
+    #[test]
+    fn splits_compare_every_ten_words() {
+        let ours: Vec<u64> = (1..=15).map(|i| i * 1000).collect();
+        let theirs: Vec<u64> = (1..=15).map(|i| i * 1500).collect();
+        let ghost = Ghost {
+            seed: 1,
+            wpm: 40,
+            keys: finished_at(&theirs),
+        };
+        assert_eq!(splits(&finished_at(&ours), &ghost), vec![5.0, 2.5]);
+    }
+
+    #[test]
+    fn splits_stop_at_the_shorter_run() {
+        let ghost = Ghost {
+            seed: 1,
+            wpm: 40,
+            keys: finished_at(&[1000, 2000, 3000]),
+        };
+        assert_eq!(splits(&finished_at(&[1500]), &ghost), vec![-0.5]);
+        assert!(splits(&[], &ghost).is_empty());
+    }
+}
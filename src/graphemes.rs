@@ -0,0 +1,59 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// User visible characters of a word, accents and emoji sequences stay together
+pub fn graphemes(text: &str) -> Vec<&str> {
+    text.graphemes(true).collect()
+}
+/// Number of user visible characters, used for the cursor position
+pub fn grapheme_count(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+/// Removes the last user visible character, returns false when empty
+pub fn pop_grapheme(text: &mut String) -> bool {
+    match text.grapheme_indices(true).next_back() {
+        Some((i, _)) => {
+            text.truncate(i);
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unicode_width::UnicodeWidthStr;
+
+    #[test]
+    fn decomposed_accent_is_one_character() {
+        let word = "cafe\u{301}";
+        assert_eq!(grapheme_count(word), 4);
+        assert_eq!(graphemes(word)[3], "e\u{301}");
+    }
+
+    #[test]
+    fn backspace_removes_the_whole_character() {
+        let mut word = "cafe\u{301}".to_string();
+        assert!(pop_grapheme(&mut word));
+        assert_eq!(word, "caf");
+        let mut empty = String::new();
+        assert!(!pop_grapheme(&mut empty));
+    }
+
+    #[test]
+    fn emoji_sequence_is_one_character() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let mut word = format!("hi{}", family);
+        assert_eq!(grapheme_count(&word), 3);
+        assert!(pop_grapheme(&mut word));
+        assert_eq!(word, "hi");
+    }
+
+    #[test]
+    fn cjk_characters_are_two_columns_wide() {
+        let word = "日本語";
+        assert_eq!(grapheme_count(word), 3);
+        assert!(graphemes(word).iter().all(|g| g.width() == 2));
+        assert_eq!(word.width(), 6);
+    }
+}
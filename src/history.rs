@@ -0,0 +1,91 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::PathBuf,
+};
+
+use crate::data_dir;
+
+/// One finished round, every round is kept here including zen rounds
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    pub date: String,
+    pub time: String,
+    pub wpm: usize,
+    pub accuracy: f64,
+    pub seconds: u64,
+    pub category: String,          // leaderboard category, or zen for zen rounds
+    pub pace: Option<(u64, bool)>, // pace caret WPM and whether it was beaten
+    pub paused: bool,              // round was paused at least once
+}
+impl HistoryEntry {
+    /// Parses a `date time wpm accuracy seconds category [key=value...]` line
+    fn parse(line: &str) -> Option<HistoryEntry> {
+        let mut parts = line.split_whitespace();
+        let mut entry = HistoryEntry {
+            date: parts.next()?.to_string(),
+            time: parts.next()?.to_string(),
+            wpm: parts.next()?.parse().ok()?,
+            accuracy: parts.next()?.parse().ok()?,
+            seconds: parts.next()?.parse().ok()?,
+            category: parts.next()?.to_string(),
+            pace: None,
+            paused: false,
+        };
+        // Optional fields, unknown keys are ignored
+        for field in parts {
+            match field.split_once('=') {
+                Some(("pace", value)) => {
+                    if let Some((wpm, outcome)) = value.split_once('/') {
+                        entry.pace = wpm.parse().ok().map(|wpm| (wpm, outcome == "beat"));
+                    }
+                }
+                Some(("paused", value)) => entry.paused = value == "yes",
+                _ => {}
+            }
+        }
+        Some(entry)
+    }
+}
+/// File every finished round is appended to
+fn history_path() -> Option<PathBuf> {
+    Some(data_dir()?.join("history.txt"))
+}
+/// Reads saved rounds oldest first, malformed lines are skipped
+pub fn load_history() -> Vec<HistoryEntry> {
+    history_path()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .map(|contents| contents.lines().filter_map(HistoryEntry::parse).collect())
+        .unwrap_or_default()
+}
+/// Adds a round to the end of the history file
+pub fn append_history(entry: &HistoryEntry) {
+    let Some(path) = history_path() else {
+        return;
+    };
+    if let Some(dir) = path.parent()
+        && let Err(e) = fs::create_dir_all(dir)
+    {
+        eprintln!("Failed to create directory for history file: {}", e);
+        return;
+    }
+    let mut line = format!(
+        "{} {} {} {:.1} {} {}",
+        entry.date, entry.time, entry.wpm, entry.accuracy, entry.seconds, entry.category
+    );
+    if let Some((wpm, beaten)) = entry.pace {
+        let outcome = if beaten { "beat" } else { "missed" };
+        line.push_str(&format!(" pace={}/{}", wpm, outcome));
+    }
+    if entry.paused {
+        line.push_str(" paused=yes");
+    }
+    let result = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{}", line));
+    if let Err(e) = result {
+        eprintln!("Error writing to history.txt: {}", e);
+    }
+}
@@ -0,0 +1,164 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use crate::data_dir;
+
+/// Keyboard rows drawn on the end screen, tab and enter go at the ends and the space bar under them
+pub const KEY_ROWS: [&str; 4] = [
+    "`1234567890-=",
+    "qwertyuiop[]\\",
+    "asdfghjkl;'",
+    "zxcvbnm,./",
+];
+/// Gaps longer than this are a break in typing rather than key latency
+const MAX_LATENCY_MS: u64 = 2000;
+
+/// Presses of one key and how they went
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KeyStats {
+    pub presses: usize,
+    pub errors: usize,
+    pub latency_ms: u64, // total time taken to reach this key
+    pub timed: usize,    // presses with a latency, the first key of a round has none
+}
+impl KeyStats {
+    /// Share of presses that were wrong, None when the key was never needed
+    pub fn error_rate(&self) -> Option<f64> {
+        (self.presses > 0).then(|| self.errors as f64 / self.presses as f64)
+    }
+    /// Average milliseconds taken to reach this key
+    pub fn latency(&self) -> Option<f64> {
+        (self.timed > 0).then(|| self.latency_ms as f64 / self.timed as f64)
+    }
+}
+/// Per key totals, keyed by the key that should have been pressed
+#[derive(Debug, Default, Clone)]
+pub struct KeyHeatmap {
+    pub keys: HashMap<char, KeyStats>,
+}
+impl KeyHeatmap {
+    /// Counts a press of the key for `expected`, `latency_ms` is the gap since the last key
+    pub fn record(&mut self, expected: char, correct: bool, latency_ms: Option<u64>) {
+        let stats = self.keys.entry(base_key(expected)).or_default();
+        stats.presses += 1;
+        stats.errors += usize::from(!correct);
+        if let Some(ms) = latency_ms.filter(|ms| *ms <= MAX_LATENCY_MS) {
+            stats.latency_ms += ms;
+            stats.timed += 1;
+        }
+    }
+    /// Adds another set of totals to these
+    pub fn merge(&mut self, other: &KeyHeatmap) {
+        for (key, theirs) in &other.keys {
+            let ours = self.keys.entry(*key).or_default();
+            ours.presses += theirs.presses;
+            ours.errors += theirs.errors;
+            ours.latency_ms += theirs.latency_ms;
+            ours.timed += theirs.timed;
+        }
+    }
+    /// Totals for a key, empty when it was never needed
+    pub fn get(&self, key: char) -> KeyStats {
+        self.keys.get(&key).copied().unwrap_or_default()
+    }
+    /// Average latency over every key, used as the middle of the latency colours
+    pub fn average_latency(&self) -> Option<f64> {
+        let timed: usize = self.keys.values().map(|s| s.timed).sum();
+        let total: u64 = self.keys.values().map(|s| s.latency_ms).sum();
+        (timed > 0).then(|| total as f64 / timed as f64)
+    }
+    /// Reads the totals from every finished round, malformed lines are skipped
+    pub fn load() -> KeyHeatmap {
+        let mut heatmap = KeyHeatmap::default();
+        let Some(contents) = keys_path().and_then(|p| fs::read_to_string(p).ok()) else {
+            return heatmap;
+        };
+        for line in contents.lines() {
+            // <key> <presses> <errors> <latency ms> <timed presses>
+            let mut parts = line.split_whitespace();
+            let key = match parts.next() {
+                Some("space") => ' ',
+                Some("tab") => '\t',
+                Some("enter") => '\n',
+                Some(key) if key.chars().count() == 1 => key.chars().next().unwrap_or(' '),
+                _ => continue,
+            };
+            let numbers: Option<Vec<u64>> = parts.map(|p| p.parse().ok()).collect();
+            let Some([presses, errors, latency_ms, timed]) = numbers.as_deref() else {
+                continue;
+            };
+            heatmap.keys.insert(
+                key,
+                KeyStats {
+                    presses: *presses as usize,
+                    errors: *errors as usize,
+                    latency_ms: *latency_ms,
+                    timed: *timed as usize,
+                },
+            );
+        }
+        heatmap
+    }
+    /// Saves the totals, replacing the previous file
+    pub fn save(&self) {
+        let Some(path) = keys_path() else {
+            return;
+        };
+        if let Some(dir) = path.parent()
+            && let Err(e) = fs::create_dir_all(dir)
+        {
+            eprintln!("Failed to create directory for key stats: {}", e);
+            return;
+        }
+        let mut keys: Vec<_> = self.keys.iter().collect();
+        keys.sort_by_key(|(key, _)| **key);
+        let lines: Vec<String> = keys
+            .iter()
+            .map(|(key, s)| {
+                let key = match key {
+                    ' ' => "space".to_string(),
+                    '\t' => "tab".to_string(),
+                    '\n' => "enter".to_string(),
+                    key => key.to_string(),
+                };
+                format!(
+                    "{} {} {} {} {}",
+                    key, s.presses, s.errors, s.latency_ms, s.timed
+                )
+            })
+            .collect();
+        if let Err(e) = fs::write(path, lines.join("\n") + "\n") {
+            eprintln!("Error writing key stats: {}", e);
+        }
+    }
+}
+/// Key that types a character, shifted symbols belong to their unshifted key
+pub fn base_key(c: char) -> char {
+    match c {
+        '~' => '`',
+        '!' => '1',
+        '@' => '2',
+        '#' => '3',
+        '$' => '4',
+        '%' => '5',
+        '^' => '6',
+        '&' => '7',
+        '*' => '8',
+        '(' => '9',
+        ')' => '0',
+        '_' => '-',
+        '+' => '=',
+        '{' => '[',
+        '}' => ']',
+        '|' => '\\',
+        ':' => ';',
+        '"' => '\'',
+        '<' => ',',
+        '>' => '.',
+        '?' => '/',
+        c => c.to_ascii_lowercase(),
+    }
+}
+/// File holding key totals across every finished round
+fn keys_path() -> Option<PathBuf> {
+    Some(data_dir()?.join("keys.txt"))
+}
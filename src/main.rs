@@ -1,25 +1,90 @@
+mod books;
+mod cli;
+mod code;
+mod daily;
+mod english;
+mod ghost;
+mod graphemes;
+mod history;
+mod keyboard;
+mod markov;
+mod modifiers;
+mod packs;
+mod quotes;
+mod stats;
+mod survival;
+mod text_file;
+
 use chrono::Local;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
-use rand::prelude::IndexedRandom;
+use crossterm::{
+    event::{self, DisableFocusChange, EnableFocusChange, Event, KeyCode, KeyEvent, KeyEventKind},
+    execute,
+};
+use rand::{Rng, SeedableRng, prelude::IndexedRandom, rngs::StdRng};
 use ratatui::{
     DefaultTerminal, Frame,
     buffer::Buffer,
     layout::{Alignment, Rect},
     prelude::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
-    symbols::border,
+    symbols::{Marker, border},
     text::{Line, Span, Text},
-    widgets::{Block, BorderType, Borders, Paragraph, Widget},
+    widgets::{
+        Axis, Block, BorderType, Borders, Chart, Dataset, GraphType, LegendPosition, Paragraph,
+        Widget,
+    },
 };
 use std::{
-    collections::VecDeque,
+    collections::{HashMap, VecDeque},
     fs::{self, File},
     io::{self, Write},
+    path::PathBuf,
     time::{Duration, Instant},
     vec,
 };
 use unicode_width::UnicodeWidthStr;
 
+use books::Book;
+use chrono::NaiveDate;
+use cli::CliArgs;
+use code::{CodeLanguage, TAB_WIDTH};
+use daily::DailyResult;
+use english::{EnglishSize, ZipfSampler};
+use ghost::{Ghost, Keystroke};
+use graphemes::{grapheme_count, graphemes, pop_grapheme};
+use history::HistoryEntry;
+use keyboard::{KEY_ROWS, KeyHeatmap, KeyStats};
+use markov::{Corpus, MarkovModel};
+use modifiers::TextModifiers;
+use packs::WordPack;
+use quotes::{Quote, QuoteLength};
+use stats::{CHARS_PER_WORD, CharStats, Sample, Wpm};
+use survival::Survival;
+use text_file::TextFile;
+
+/// Number of recent words that cannot be repeated during word generation
+pub const RECENT_WORD_WINDOW: usize = 10;
+/// Built in themes, user packs are indexed after these
+pub const BUILTIN_THEME_COUNT: usize = 7;
+/// Seeds picked for unseeded rounds stay short so they are easy to share
+const RANDOM_SEED_LIMIT: u64 = 1_000_000;
+/// Round time choices in seconds on the options screen, the last option is a custom time
+const ROUND_TIMES: [u64; 3] = [30, 60, 120];
+/// Endurance session lengths in minutes
+const ENDURANCE_MINUTES: [u64; 3] = [5, 10, 15];
+/// Width of the WPM bars in the split table
+const SPLIT_BAR_WIDTH: usize = 20;
+/// Longest custom round time in seconds
+pub const MAX_ROUND_SECONDS: u64 = 3600;
+/// Fixed pace used until the user types their own
+const DEFAULT_PACE_WPM: u64 = 60;
+/// Fastest fixed pace that can be typed in
+const MAX_PACE_WPM: u64 = 300;
+/// Word count choices on the options screen, the last option is a custom count
+const WORD_COUNTS: [usize; 4] = [10, 25, 50, 100];
+/// Largest custom word count, keeps the generated text a sensible size
+const MAX_WORD_GOAL: u64 = 1000;
+
 /// Determines which screen to render
 #[derive(Debug, Default)]
 pub enum CurrentScreen {
@@ -27,14 +92,101 @@ pub enum CurrentScreen {
     Main,
     EndRound,
     ShowOptions,
+    Books,
+    History,
+    Daily,
 }
-/// Used for user round time config
-#[derive(Debug, Default)]
-pub enum RoundTime {
+/// Used for user text source config
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum TextSource {
     #[default]
-    Default,
-    Min,
-    TwoMin,
+    Words,
+    Quotes,
+    Code,
+    Book,
+    Prose,
+    File, // only chosen with --text
+}
+impl TextSource {
+    /// Maps config index to text source
+    pub fn from_index(index: usize) -> TextSource {
+        match index {
+            1 => TextSource::Quotes,
+            2 => TextSource::Code,
+            3 => TextSource::Book,
+            4 => TextSource::Prose,
+            _ => TextSource::Words,
+        }
+    }
+}
+/// Used for user error handling config
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum ErrorMode {
+    #[default]
+    Normal,
+    SuddenDeath, // round ends on the first mistake
+    Strict,      // wrong keys are rejected until the word is typed exactly
+}
+impl ErrorMode {
+    /// Maps config index to error mode
+    pub fn from_index(index: usize) -> ErrorMode {
+        match index {
+            1 => ErrorMode::SuddenDeath,
+            2 => ErrorMode::Strict,
+            _ => ErrorMode::Normal,
+        }
+    }
+}
+/// Rows shown on the user options screen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OptionRow {
+    RoundMode,
+    RoundTime,
+    WordCount,
+    Endurance,
+    ErrorMode,
+    Pace,
+    Ghost,
+    AutoPause,
+    TextSource,
+    TextTheme,
+    QuoteLength,
+    CodeLanguage,
+    SkipIndent,
+    Punctuation,
+    Numbers,
+    Capitalization,
+    Book,
+    Corpus,
+    Seed,
+    Save,
+}
+impl OptionRow {
+    /// Heading shown above the row
+    pub fn label(&self) -> &'static str {
+        match self {
+            OptionRow::RoundMode => "Round Mode",
+            OptionRow::RoundTime => "Round Time",
+            OptionRow::WordCount => "Word Count",
+            OptionRow::Endurance => "Session Length",
+            OptionRow::ErrorMode => "Errors",
+            OptionRow::Pace => "Pace Caret",
+            OptionRow::Ghost => "Ghost Race",
+            OptionRow::AutoPause => "Auto Pause",
+            OptionRow::TextSource => "Text Source",
+            OptionRow::TextTheme => "Word Theme",
+            OptionRow::QuoteLength => "Quote Length",
+            OptionRow::CodeLanguage => "Code Language",
+            OptionRow::SkipIndent => "Skip Indentation",
+            OptionRow::Punctuation => "Punctuation",
+            OptionRow::Numbers => "Numbers",
+            OptionRow::Capitalization => "Capitalization",
+            OptionRow::Book => "Book",
+            OptionRow::Corpus => "Corpus",
+            OptionRow::Seed => "Seed",
+            OptionRow::Save => "Save",
+        }
+    }
 }
 /// Used for user text theme config
 #[derive(Debug, Default)]
@@ -44,10 +196,28 @@ pub enum TextTheme {
     Lorem,
     Tech,
     Food,
+    English(EnglishSize),
+    Pack(WordPack),
 }
 impl TextTheme {
+    /// Maps config index to theme, indexes past the built in themes select user packs
+    pub fn from_index(index: usize, packs: &[WordPack]) -> TextTheme {
+        match index {
+            0 => TextTheme::Default,
+            1 => TextTheme::Lorem,
+            2 => TextTheme::Tech,
+            3 => TextTheme::Food,
+            4 => TextTheme::English(EnglishSize::Top200),
+            5 => TextTheme::English(EnglishSize::Top1k),
+            6 => TextTheme::English(EnglishSize::Top10k),
+            _ => match packs.get(index - BUILTIN_THEME_COUNT) {
+                Some(pack) => TextTheme::Pack(pack.clone()),
+                None => TextTheme::Default, // pack removed since config was chosen
+            },
+        }
+    }
     /// Returns custom word lists based on selection
-    pub fn word_list(&self) -> Vec<&'static str> {
+    pub fn word_list(&self) -> Vec<&str> {
         match self {
             // Default = random no correlation
             TextTheme::Default => vec![
@@ -581,6 +751,30 @@ impl TextTheme {
                 "veal",
                 "churro",
             ],
+            // Most frequent English words, in rank order
+            TextTheme::English(size) => english::ranked_words(*size),
+            // User supplied word pack
+            TextTheme::Pack(pack) => pack.words.iter().map(String::as_str).collect(),
+        }
+    }
+    /// Frequency themes draw common words more often
+    pub fn is_weighted(&self) -> bool {
+        matches!(self, TextTheme::English(_))
+    }
+    /// Short name used in file names, pack names keep only letters and digits
+    pub fn key(&self) -> String {
+        match self {
+            TextTheme::Default => "default".to_string(),
+            TextTheme::Lorem => "lorem".to_string(),
+            TextTheme::Tech => "tech".to_string(),
+            TextTheme::Food => "food".to_string(),
+            TextTheme::English(EnglishSize::Top200) => "en200".to_string(),
+            TextTheme::English(EnglishSize::Top1k) => "en1k".to_string(),
+            TextTheme::English(EnglishSize::Top10k) => "en10k".to_string(),
+            TextTheme::Pack(pack) => {
+                let name: String = pack.name.chars().filter(|c| c.is_alphanumeric()).collect();
+                format!("pack-{}", name.to_lowercase())
+            }
         }
     }
 }
@@ -589,119 +783,427 @@ impl TextTheme {
 pub struct TopScore {
     pub date: String,
     pub wpm_score: usize,
+    pub category: String,  // text source and modifiers, e.g. words+punct
+    pub seed: Option<u64>, // missing for scores saved before seeds existed
+    pub paused: bool,      // round was paused at least once
 }
 /// Data class for single round stats result
 #[derive(Debug)]
 pub struct RoundResult {
     pub correct_words: usize,
-    pub total_chars: usize,
-    pub correct_chars: usize,
-    pub incorrect_chars: usize,
+    pub chars: CharStats, // includes the word in progress when the round ended
+    pub wpm: Wpm,
     pub percentage_words: f64,
-    pub percentage_chars: f64,
+    pub total_symbols: usize,
+    pub correct_symbols: usize,
+    pub percentage_symbols: f64,
 }
 /// For interactions in user options theme and saving choices
 #[derive(Debug, Default, Clone)]
 pub struct ConfigIndex {
+    pub round_mode_index: usize,
     pub round_time_index: usize,
+    pub word_count_index: usize,
+    pub endurance_index: usize,
+    pub error_mode_index: usize,
+    pub pace_index: usize,
+    pub ghost_index: usize,
+    pub auto_pause_index: usize,
+    pub text_source_index: usize,
     pub text_theme_index: usize,
+    pub quote_length_index: usize,
+    pub code_language_index: usize,
+    pub skip_indent_index: usize,
+    pub punctuation_index: usize,
+    pub numbers_index: usize,
+    pub capitalization_index: usize,
+    pub book_index: usize,
+    pub corpus_index: usize,
+    pub seed: Option<u64>,           // fixed seed, random each round when unset
+    pub custom_words: Option<u64>,   // word count typed in for the custom option
+    pub custom_seconds: Option<u64>, // round time typed in for the custom option
+    pub pace_wpm: Option<u64>,       // fixed pace typed in on the options screen
+    pub text_pack: Option<String>,   // word pack theme, saved by name as packs can move
     pub choice_index: usize,
 }
+impl ConfigIndex {
+    /// File input for saved user config, unknown keys are ignored
+    pub fn load() -> Option<ConfigIndex> {
+        let contents = fs::read_to_string(data_dir()?.join("config.txt")).ok()?;
+        let mut config = ConfigIndex::default();
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            match key.trim() {
+                "seed" => config.seed = value.trim().parse::<u64>().ok(),
+                "custom_words" => config.custom_words = value.trim().parse::<u64>().ok(),
+                "custom_time" => config.custom_seconds = value.trim().parse::<u64>().ok(),
+                "pace_wpm" => config.pace_wpm = value.trim().parse::<u64>().ok(),
+                "text_pack" => config.text_pack = Some(value.trim().to_string()),
+                _ => {}
+            }
+            let Ok(value) = value.trim().parse::<usize>() else {
+                continue;
+            };
+            match key.trim() {
+                "round_mode" => config.round_mode_index = value,
+                "round_time" => config.round_time_index = value,
+                "word_count" => config.word_count_index = value,
+                "endurance" => config.endurance_index = value,
+                "error_mode" => config.error_mode_index = value,
+                "pace" => config.pace_index = value,
+                "ghost" => config.ghost_index = value,
+                "auto_pause" => config.auto_pause_index = value,
+                "text_source" => config.text_source_index = value,
+                "text_theme" => config.text_theme_index = value,
+                "quote_length" => config.quote_length_index = value,
+                "code_language" => config.code_language_index = value,
+                "skip_indent" => config.skip_indent_index = value,
+                "punctuation" => config.punctuation_index = value,
+                "numbers" => config.numbers_index = value,
+                "capitalization" => config.capitalization_index = value,
+                "book" => config.book_index = value,
+                "corpus" => config.corpus_index = value,
+                _ => {}
+            }
+        }
+        Some(config)
+    }
+    /// File output so config survives restarts
+    pub fn save(&self) {
+        let config_dir = match data_dir() {
+            Some(dir) => dir,
+            None => return,
+        };
+        if let Err(e) = fs::create_dir_all(&config_dir) {
+            eprintln!("Failed to create directory for config file: {}", e);
+            return;
+        }
+        let mut lines = vec![
+            format!("round_mode={}", self.round_mode_index),
+            format!("round_time={}", self.round_time_index),
+            format!("word_count={}", self.word_count_index),
+            format!("endurance={}", self.endurance_index),
+            format!("error_mode={}", self.error_mode_index),
+            format!("pace={}", self.pace_index),
+            format!("ghost={}", self.ghost_index),
+            format!("auto_pause={}", self.auto_pause_index),
+            format!("text_source={}", self.text_source_index),
+            format!("text_theme={}", self.text_theme_index),
+            format!("quote_length={}", self.quote_length_index),
+            format!("code_language={}", self.code_language_index),
+            format!("skip_indent={}", self.skip_indent_index),
+            format!("punctuation={}", self.punctuation_index),
+            format!("numbers={}", self.numbers_index),
+            format!("capitalization={}", self.capitalization_index),
+            format!("book={}", self.book_index),
+            format!("corpus={}", self.corpus_index),
+        ];
+        if let Some(seed) = self.seed {
+            lines.push(format!("seed={}", seed));
+        }
+        if let Some(words) = self.custom_words {
+            lines.push(format!("custom_words={}", words));
+        }
+        if let Some(seconds) = self.custom_seconds {
+            lines.push(format!("custom_time={}", seconds));
+        }
+        if let Some(wpm) = self.pace_wpm {
+            lines.push(format!("pace_wpm={}", wpm));
+        }
+        if let Some(pack) = &self.text_pack {
+            lines.push(format!("text_pack={}", pack));
+        }
+        if let Err(e) = fs::write(config_dir.join("config.txt"), lines.join("\n") + "\n") {
+            eprintln!("Error writing to config.txt: {}", e);
+        }
+    }
+    /// Length of a timed round in seconds
+    pub fn round_seconds(&self) -> u64 {
+        if self.round_mode_index == 4 {
+            return ENDURANCE_MINUTES
+                .get(self.endurance_index)
+                .unwrap_or(&ENDURANCE_MINUTES[0])
+                * 60;
+        }
+        match ROUND_TIMES.get(self.round_time_index) {
+            Some(seconds) => *seconds,
+            None => match self.custom_seconds {
+                Some(seconds) if seconds > 0 => seconds.min(MAX_ROUND_SECONDS),
+                _ => ROUND_TIMES[0],
+            },
+        }
+    }
+    /// Zen rounds have no clock and end when the user chooses
+    pub fn is_zen(&self) -> bool {
+        self.round_mode_index == 2
+    }
+    /// Survival rounds give each word its own timer and end when the lives run out
+    pub fn is_survival(&self) -> bool {
+        self.round_mode_index == 3
+    }
+    /// Words to type in a word count round, None for timed rounds
+    pub fn word_goal(&self) -> Option<usize> {
+        if self.round_mode_index != 1 {
+            return None;
+        }
+        Some(match WORD_COUNTS.get(self.word_count_index) {
+            Some(count) => *count,
+            None => match self.custom_words {
+                Some(words) if words > 0 => words.min(MAX_WORD_GOAL) as usize,
+                _ => WORD_COUNTS[1],
+            },
+        })
+    }
+}
 /// Main application structure
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct App {
-    pub char_index: usize,                 // current char
-    pub word_index: usize,                 // current word
-    pub typed_words: Vec<String>,          // words typed tracking
-    pub target_words: Vec<String>,         // random word gen list
-    pub start_time: Option<Instant>,       // round timing
-    pub time_remaining: u64,               // count down
-    pub exit: bool,                        // exit app loop
-    pub current_screen: CurrentScreen,     // screen state
-    pub round_time: RoundTime,             // round time theme choice
-    pub text_theme: TextTheme,             // text theme choice
+    pub char_index: usize,                      // current char
+    pub word_index: usize,                      // current word
+    pub typed_words: Vec<String>,               // words typed tracking
+    pub target_words: Vec<String>,              // random word gen list
+    pub start_time: Option<Instant>,            // round timing
+    pub paused_at: Option<Instant>,             // set while the round is paused
+    pub paused_for: Duration,                   // total paused time, left out of the round time
+    pub was_paused: bool,                       // round was paused at least once
+    pub abandoned: bool, // round was left for the options screen, saving starts a new one
+    pub time_remaining: u64, // count down
+    pub exit: bool,      // exit app loop
+    pub current_screen: CurrentScreen, // screen state
+    pub round_time: u64, // timed round length in seconds
+    pub endurance: bool, // endurance session, ranked by its length
+    pub word_goal: Option<usize>, // words to type in a word count round
+    pub zen: bool,       // untimed round ended with ctrl + e
+    pub survival: Option<Survival>, // lives and word timer for survival rounds
+    pub splits: Vec<CharStats>, // per minute totals built up during the round
+    pub samples: Vec<Sample>, // totals taken every second for the speed chart
+    pub mistakes: usize, // wrong keys pressed, kept even when fixed later
+    pub key_stats: KeyHeatmap, // every key press this round against the key expected
+    pub key_totals: KeyHeatmap, // key presses across every finished round
+    pub last_key_ms: Option<u64>, // round time of the last key, for key latency
+    pub heatmap_view: usize, // errors or latency, this round or all rounds
+    pub word_minutes: Vec<usize>, // minute each finished word was counted in
+    pub error_mode: ErrorMode, // how mistakes are handled
+    pub failed_at: Option<(usize, usize)>, // word and character that ended a sudden death round
+    pub rejected_keys: usize, // wrong keys refused in strict mode
+    pub pace_wpm: Option<u64>, // speed of the pace caret, None when off
+    pub keystrokes: Vec<Keystroke>, // cursor position after every key press
+    pub ghost: Option<Ghost>, // best run raced against this round
+    pub daily: Option<NaiveDate>, // date of the daily challenge being played
+    pub daily_official: bool, // first attempt of the day, later ones are practice
+    pub daily_results: Vec<DailyResult>, // official results for the daily screen
+    pub text_source: TextSource, // text source choice
+    pub text_theme: TextTheme, // text theme choice
+    pub passages: Vec<(usize, &'static Quote)>, // quote start word index
+    pub code_language: CodeLanguage, // code snippet language choice
+    pub skip_indent: bool, // prefill code indentation
+    pub modifiers: TextModifiers, // punctuation, numbers and caps
+    pub round_elapsed: Duration, // final round length
     pub top_scores: Option<Vec<TopScore>>, // top scores from file I/O
-    pub config: ConfigIndex,               // config state
-    pub cooldown_start: Option<Instant>,   // disable key press post round
+    pub config: ConfigIndex, // config state
+    pub cooldown_start: Option<Instant>, // disable key press post round
+    pub word_packs: Vec<WordPack>, // user packs from data dir
+    pub pack_errors: Vec<String>, // packs skipped on load
+    pub seed: u64,       // seed for this round's text
+    pub text_file: Option<TextFile>, // text from --text, typed in order
+    pub books: Vec<Book>, // imported books with saved progress
+    pub books_loaded: bool, // books are only read once book mode or the books screen needs them
+    pub active_book: Option<usize>, // book being typed this round
+    pub book_start: usize, // book word index the round started at
+    pub book_choice: usize, // selected book on the books screen
+    pub markov: Option<MarkovModel>, // sentence generator for prose rounds
+    pub history: Vec<HistoryEntry>, // finished rounds for the history screen
+    pub rng: StdRng,     // drives all text generation for the round
 }
 /// Main app functionality
 impl App {
-    /// Init new app
-    pub fn new() -> Self {
-        let text_theme = TextTheme::Default;
-        let words_list = text_theme.word_list();
-        let target_words = generate_words(&words_list, 60);
-        Self {
-            char_index: 0,
-            word_index: 0,
-            typed_words: vec![String::new()],
-            target_words,
-            start_time: None,
-            time_remaining: 30,
-            exit: false,
-            current_screen: CurrentScreen::Main,
-            round_time: RoundTime::Default,
-            text_theme: TextTheme::Default,
-            top_scores: None,
-            config: ConfigIndex {
-                round_time_index: 0,
-                text_theme_index: 0,
-                choice_index: 0,
-            },
-            cooldown_start: None,
+    /// Init new app, command line options override the saved config
+    pub fn new(args: &CliArgs, text_file: Option<TextFile>) -> Self {
+        let mut config = ConfigIndex::load().unwrap_or_default();
+        if args.daily {
+            return App::new_daily(config);
+        }
+        if args.seed.is_some() {
+            config.seed = args.seed;
         }
+        // A time on the command line picks a timed round of that length
+        if let Some(seconds) = args.time {
+            config.round_mode_index = 0;
+            config.round_time_index = ROUND_TIMES.len();
+            config.custom_seconds = Some(seconds);
+        }
+        App::new_with_config(config, text_file)
     }
-    /// Init app with custom config
-    pub fn new_with_config(config: ConfigIndex) -> Self {
-        let text_theme = match config.text_theme_index {
-            0 => TextTheme::Default,
-            1 => TextTheme::Lorem,
-            2 => TextTheme::Tech,
-            3 => TextTheme::Food,
-            _ => panic!("Invalid text_theme_index {}", config.text_theme_index),
-        };
-        let words_list = text_theme.word_list();
-        let target_words = generate_words(&words_list, 60);
-        let time_remaining = match config.round_time_index {
-            0 => 30,
-            1 => 60,
-            2 => 120,
-            _ => {
-                panic!("Invalid round_time_index {}", config.round_time_index);
+    /// Init app with custom config, a text file replaces the configured text source
+    pub fn new_with_config(config: ConfigIndex, text_file: Option<TextFile>) -> Self {
+        let app = App::build(config, text_file, None);
+        // Ghost races need the same words as the recorded run
+        match &app.ghost {
+            Some(ghost) if ghost.seed != app.seed => {
+                let seed = ghost.seed;
+                App::build(app.config, app.text_file, Some(seed))
             }
+            _ => app,
+        }
+    }
+    /// Today's daily challenge, the saved config is kept for the rounds after it
+    pub fn new_daily(config: ConfigIndex) -> Self {
+        let date = daily::today();
+        let mut app = App::build(daily::config_for(date), None, None);
+        app.daily = Some(date);
+        app.config = config;
+        app
+    }
+    /// Builds the round, `seed` overrides the configured seed
+    fn build(mut config: ConfigIndex, text_file: Option<TextFile>, seed: Option<u64>) -> Self {
+        let (word_packs, pack_errors) = packs::load_word_packs();
+        // Packs are looked up by name, a missing pack falls back to the default theme
+        if let Some(name) = &config.text_pack {
+            config.text_theme_index = word_packs
+                .iter()
+                .position(|pack| &pack.name == name)
+                .map_or(0, |i| BUILTIN_THEME_COUNT + i);
+        }
+        let text_theme = TextTheme::from_index(config.text_theme_index, &word_packs);
+        let books_loaded = TextSource::from_index(config.text_source_index) == TextSource::Book;
+        let books = match books_loaded {
+            true => books::load_books(),
+            false => vec![],
         };
-        let round_time = match config.round_time_index {
-            0 => RoundTime::Default,
-            1 => RoundTime::Min,
-            2 => RoundTime::TwoMin,
-            _ => {
-                panic!("Invalid round_time_index {}", config.round_time_index);
-            }
+        let text_source = match text_file {
+            Some(_) => TextSource::File,
+            // Nothing to type without an imported book
+            None => match TextSource::from_index(config.text_source_index) {
+                TextSource::Book if books.is_empty() => TextSource::Words,
+                source => source,
+            },
         };
-        let text_theme = match config.text_theme_index {
-            0 => TextTheme::Default,
-            1 => TextTheme::Lorem,
-            2 => TextTheme::Tech,
-            3 => TextTheme::Food,
-            _ => {
-                panic!("Invalid text_theme_index {}", config.text_theme_index);
+        let active_book = (text_source == TextSource::Book)
+            .then(|| config.book_index.min(books.len().saturating_sub(1)));
+        let book_start = active_book.map_or(0, |i| books[i].position);
+        let markov = (text_source == TextSource::Prose)
+            .then(|| MarkovModel::load(Corpus::from_index(config.corpus_index)));
+        let modifiers = TextModifiers::from_config(&config);
+        let chosen_seed = seed.or(config.seed);
+        let seed = chosen_seed.unwrap_or_else(|| rand::rng().random_range(0..RANDOM_SEED_LIMIT));
+        let mut rng = StdRng::seed_from_u64(seed);
+        let round_time = config.round_seconds();
+        let word_goal = config.word_goal();
+        let (target_words, passages) = match text_source {
+            TextSource::Words => {
+                let words = generate_words(
+                    &text_theme.word_list(),
+                    text_theme.is_weighted(),
+                    60,
+                    &mut rng,
+                );
+                (modifiers.apply(words, None, &mut rng), vec![])
             }
+            TextSource::Quotes => {
+                // Roughly one word per second so fast typists rarely run out
+                let length = QuoteLength::from_index(config.quote_length_index);
+                let picked = quotes::pick_passages(
+                    length,
+                    word_goal.unwrap_or(round_time as usize),
+                    &mut rng,
+                );
+                let mut words = Vec::new();
+                let mut passages = Vec::new();
+                for quote in picked {
+                    passages.push((words.len(), quote));
+                    words.extend(quote.words());
+                }
+                (words, passages)
+            }
+            TextSource::Code => {
+                let language = CodeLanguage::from_index(config.code_language_index);
+                (code::snippet_lines(language, &mut rng), vec![])
+            }
+            TextSource::Book => match active_book {
+                Some(i) => (
+                    books[i].chunk(book_start, text_file::FIRST_CHUNK_WORDS),
+                    vec![],
+                ),
+                None => (vec![], vec![]),
+            },
+            TextSource::Prose => match &markov {
+                Some(model) => (model.generate(60, &mut rng), vec![]),
+                None => (vec![], vec![]),
+            },
+            TextSource::File => match &text_file {
+                Some(file) => (file.chunk(0, text_file::FIRST_CHUNK_WORDS), vec![]),
+                None => (vec![], vec![]),
+            },
         };
-        Self {
+        let mut app = Self {
             char_index: 0,
             word_index: 0,
             typed_words: vec![String::new()],
             target_words,
             start_time: None,
-            time_remaining: time_remaining,
+            paused_at: None,
+            paused_for: Duration::ZERO,
+            was_paused: false,
+            abandoned: false,
+            time_remaining: round_time,
             exit: false,
             current_screen: CurrentScreen::Main,
-            round_time: round_time,
-            text_theme: text_theme,
+            round_time,
+            endurance: config.round_mode_index == 4,
+            word_goal,
+            zen: config.is_zen(),
+            survival: config.is_survival().then(Survival::default),
+            splits: vec![],
+            samples: vec![],
+            mistakes: 0,
+            key_stats: KeyHeatmap::default(),
+            key_totals: KeyHeatmap::default(),
+            last_key_ms: None,
+            heatmap_view: 0,
+            word_minutes: vec![],
+            error_mode: ErrorMode::from_index(config.error_mode_index),
+            failed_at: None,
+            rejected_keys: 0,
+            pace_wpm: None,
+            keystrokes: vec![],
+            ghost: None,
+            daily: None,
+            daily_official: false,
+            daily_results: vec![],
+            text_source,
+            text_theme,
+            passages,
+            code_language: CodeLanguage::from_index(config.code_language_index),
+            skip_indent: text_source == TextSource::Code && config.skip_indent_index == 1,
+            modifiers,
+            round_elapsed: Duration::ZERO,
             top_scores: None,
-            config: config,
+            config,
             cooldown_start: None,
+            word_packs,
+            pack_errors,
+            seed,
+            rng,
+            text_file,
+            book_choice: active_book.unwrap_or(0),
+            books,
+            books_loaded,
+            active_book,
+            book_start,
+            markov,
+            history: vec![],
+        };
+        app.fill_word_goal();
+        app.prefill_indent();
+        app.pace_wpm = app.resolve_pace();
+        if app.config.ghost_index == 1 && app.uses_seed() {
+            // A chosen seed wins over a ghost recorded on other words
+            app.ghost = Ghost::load(&app.ghost_category())
+                .filter(|ghost| chosen_seed.is_none_or(|seed| seed == ghost.seed));
         }
+        app
     }
     /// Run main app
     pub fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
@@ -714,6 +1216,7 @@ impl App {
             terminal.draw(|f| self.draw(f))?; // render TUI
             self.handle_events()?; // handle screen events
         }
+        self.forfeit_daily();
         Ok(())
     }
     /// Render TUI
@@ -724,70 +1227,325 @@ impl App {
     fn handle_events(&mut self) -> io::Result<()> {
         // Check for new events every 0.05s
         if event::poll(Duration::from_millis(50))? {
-            if let Event::Key(key_event) = event::read()? {
-                if key_event.kind == KeyEventKind::Press {
+            match event::read()? {
+                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
                     self.handle_key_event(key_event); // Key press logic
                 }
+                // Stepping away from the terminal pauses the round when enabled
+                Event::FocusLost if self.config.auto_pause_index == 1 => self.pause(),
+                _ => {}
             }
         }
-        // Main screen specific logic
-        if let CurrentScreen::Main = self.current_screen {
-            if let Some(start) = self.start_time {
-                let elapsed = start.elapsed().as_secs();
-                // When round finished
-                if elapsed >= self.get_round_time() {
-                    let round_results = self.get_accuracy();
-                    // Calculate stats
-                    let wpm = match self.round_time {
-                        RoundTime::Default => {
-                            (self.word_index - (self.word_index - round_results.correct_words))
-                                as f64
-                                / 0.5
-                        }
-                        RoundTime::Min => {
-                            (self.word_index - (self.word_index - round_results.correct_words))
-                                as f64
-                        }
-                        RoundTime::TwoMin => {
-                            (self.word_index - (self.word_index - round_results.correct_words))
-                                as f64
-                                / 2.0
-                        }
-                    };
-                    let mut should_update = false; // true if top 10 score
-                    if let Some(scores) = &self.top_scores {
-                        if scores.len() < 10 || scores.iter().any(|s| wpm as usize > s.wpm_score) {
-                            should_update = true;
-                        }
-                    } else {
-                        should_update = true;
-                    }
-                    // If top 10
-                    if should_update {
-                        // Update local file
-                        let now = Local::now();
-                        let date = now.format("%d-%m-%Y").to_string();
-                        self.update_leaderboard_file_contents(TopScore {
-                            date: date,
-                            wpm_score: wpm as usize,
-                        });
-                    }
-                    self.current_screen = CurrentScreen::EndRound; // switch screen
-                    self.start_time = None;
-                } else {
-                    // Countdown logic
-                    self.time_remaining = self.get_round_time() - elapsed;
+        // Main screen specific logic, nothing counts down while paused
+        if let CurrentScreen::Main = self.current_screen
+            && let Some(start) = self.start_time
+            && self.paused_at.is_none()
+        {
+            // Survival words that run out of time cost a life and are skipped
+            let level = Survival::level(self.word_index);
+            if let Some(survival) = &mut self.survival {
+                let word_start = *survival.word_start.get_or_insert(start);
+                if word_start.elapsed() >= Survival::budget(level) {
+                    survival.lose_life();
+                    survival.timed_out.push(self.word_index);
+                    self.advance_word();
                 }
             }
+            self.take_samples();
+            let out_of_lives = self.survival.as_ref().is_some_and(|s| s.lives == 0);
+            let elapsed = self.elapsed().as_secs();
+            // Word count and zen rounds have no time limit
+            let time_up = self.is_timed() && elapsed >= self.round_time;
+            // When round finished or quote passages all typed
+            if time_up || out_of_lives || self.text_complete() {
+                self.finish_round();
+            } else if self.is_timed() {
+                // Countdown logic
+                self.time_remaining = self.round_time - elapsed;
+            }
         }
         Ok(())
     }
+    /// Saves the round to history and the leaderboard, then shows the end screen
+    fn finish_round(&mut self) {
+        self.round_elapsed = match self.is_timed() {
+            true => self.elapsed().min(Duration::from_secs(self.round_time)),
+            false => self.elapsed(),
+        };
+        self.record_keystroke(self.round_elapsed);
+        // The word being typed at the end still counts toward the last minute's split
+        let (typed, target) = self.word_graphemes(self.word_index);
+        if !typed.is_empty() {
+            let stats = CharStats::word(&typed, &target, false);
+            let last_moment = self.round_elapsed.saturating_sub(Duration::from_millis(1));
+            let minute = (last_moment.as_secs() / 60) as usize;
+            if self.splits.len() <= minute {
+                self.splits.resize(minute + 1, CharStats::default());
+            }
+            self.splits[minute] += stats;
+        }
+        // Last part second of the round
+        let seconds = self.round_elapsed.as_secs_f64();
+        if self
+            .samples
+            .last()
+            .is_none_or(|s| seconds - s.seconds > 0.1)
+        {
+            self.samples.push(Sample {
+                seconds,
+                chars: self.char_stats(),
+                mistakes: self.mistakes,
+            });
+        }
+        let round_results = self.get_accuracy();
+        let wpm = round_results.wpm.net;
+        let now = Local::now();
+        let date = now.format("%d-%m-%Y").to_string();
+        history::append_history(&HistoryEntry {
+            date: date.clone(),
+            time: now.format("%H:%M").to_string(),
+            wpm: wpm as usize,
+            accuracy: round_results.chars.accuracy(),
+            seconds: self.round_elapsed.as_secs(),
+            category: self.leaderboard_category(),
+            pace: self
+                .pace_lead(self.round_elapsed)
+                .zip(self.pace_wpm)
+                .map(|(lead, pace)| (pace, lead >= 0.0)),
+            paused: self.was_paused,
+        });
+        // Survival ranks by words cleared and sudden death by words before the mistake
+        let score = match (&self.survival, self.error_mode) {
+            (Some(_), _) => round_results.correct_words,
+            (None, ErrorMode::SuddenDeath) => self.word_index,
+            (None, _) => wpm as usize,
+        };
+        // Only the first daily attempt counts, it is kept apart from the leaderboard
+        if let Some(date) = self.daily {
+            self.daily_official = daily::record_daily(&DailyResult {
+                date,
+                wpm: wpm as usize,
+                accuracy: round_results.chars.accuracy(),
+            });
+        }
+        // true if top 10 score for this category, zen and daily rounds are never ranked
+        let scores = self.category_scores();
+        let should_update = !self.zen
+            && self.daily.is_none()
+            && (scores.len() < 10 || scores.iter().any(|s| score > s.wpm_score));
+        // If top 10
+        if should_update {
+            // Update local file
+            self.update_leaderboard_file_contents(TopScore {
+                date,
+                wpm_score: score,
+                category: self.leaderboard_category(),
+                seed: self.uses_seed().then_some(self.seed),
+                paused: self.was_paused,
+            });
+        }
+        self.save_ghost(wpm as usize);
+        self.key_totals = KeyHeatmap::load();
+        self.key_totals.merge(&self.key_stats);
+        self.key_totals.save();
+        self.record_book_progress();
+        self.current_screen = CurrentScreen::EndRound; // switch screen
+        self.start_time = None;
+    }
+    /// Leaving a daily round before the end still uses up the day's official attempt
+    fn forfeit_daily(&self) {
+        if let Some(date) = self.daily
+            && self.start_time.is_some()
+            && (self.word_index > 0 || self.char_index > 0)
+        {
+            let chars = self.char_stats();
+            daily::record_daily(&DailyResult {
+                date,
+                wpm: chars.wpm(self.elapsed()).net as usize,
+                accuracy: chars.accuracy(),
+            });
+        }
+    }
+    /// Ends a sudden death round, remembering where the first mistake was
+    fn fail_round(&mut self) {
+        let typed = graphemes(&self.typed_words[self.word_index]);
+        let target = graphemes(&self.target_words[self.word_index]);
+        let matching = typed
+            .iter()
+            .zip(&target)
+            .take_while(|(a, b)| a == b)
+            .count();
+        self.failed_at = Some((self.word_index, matching));
+        if self.start_time.is_some() {
+            self.finish_round();
+        }
+    }
+    /// Scores a key press against the character the target expected, or the key itself past the end
+    fn record_key(&mut self, expected: Option<&str>, pressed: char, correct: bool) {
+        let now = self.elapsed().as_millis() as u64;
+        let latency = self.last_key_ms.map(|last| now.saturating_sub(last));
+        self.last_key_ms = Some(now);
+        // Wrong keys are counted as they happen for the error markers on the chart
+        if !correct {
+            self.mistakes += 1;
+        }
+        let expected = expected.and_then(|g| g.chars().next()).unwrap_or(pressed);
+        self.key_stats.record(expected, correct, latency);
+    }
+    /// Records the totals for each whole second passed since the last sample
+    fn take_samples(&mut self) {
+        let elapsed = self.elapsed().as_secs();
+        while (self.samples.len() as u64) < elapsed {
+            self.samples.push(Sample {
+                seconds: self.samples.len() as f64 + 1.0,
+                chars: self.char_stats(),
+                mistakes: self.mistakes,
+            });
+        }
+    }
+    /// Round time so far, paused time is left out
+    fn elapsed(&self) -> Duration {
+        let Some(start) = self.start_time else {
+            return Duration::ZERO;
+        };
+        let paused_now = self.paused_at.map_or(Duration::ZERO, |p| p.elapsed());
+        stats::active_time(start.elapsed(), self.paused_for + paused_now)
+    }
+    /// Freezes a round that has started, the text is hidden until it resumes
+    fn pause(&mut self) {
+        if let CurrentScreen::Main = self.current_screen
+            && self.start_time.is_some()
+            && self.paused_at.is_none()
+        {
+            self.paused_at = Some(Instant::now());
+            self.was_paused = true;
+        }
+    }
+    /// Carries on a paused round, the survival word timer skips the pause too
+    fn resume(&mut self) {
+        let Some(paused_at) = self.paused_at.take() else {
+            return;
+        };
+        let pause = paused_at.elapsed();
+        self.paused_for += pause;
+        if let Some(word_start) = self.survival.as_mut().and_then(|s| s.word_start.as_mut()) {
+            *word_start += pause;
+        }
+    }
+    /// Remembers where the cursor is for ghost replays
+    fn record_keystroke(&mut self, elapsed: Duration) {
+        self.keystrokes.push(Keystroke {
+            ms: elapsed.as_millis() as u64,
+            word: self.word_index,
+            char: self.char_index,
+        });
+    }
+    /// Keeps this round as the category's ghost when it beats the saved run
+    fn save_ghost(&self, wpm: usize) {
+        if !self.uses_seed() || self.zen || self.failed_at.is_some() || self.word_index == 0 {
+            return;
+        }
+        let category = self.ghost_category();
+        if Ghost::load(&category).is_some_and(|ghost| ghost.wpm >= wpm) {
+            return;
+        }
+        Ghost {
+            seed: self.seed,
+            wpm,
+            keys: self.keystrokes.clone(),
+        }
+        .save(&category);
+    }
+    /// Ends the word with a space or enter, ending it early misses the next letter
+    fn submit_word(&mut self, pressed: char) {
+        let expected = graphemes(&self.target_words[self.word_index])
+            .get(self.char_index)
+            .map(|g| g.to_string());
+        self.record_key(expected.as_deref(), pressed, expected.is_none());
+        self.next_word();
+    }
+    /// Types a character into the current word
+    fn type_char(&mut self, c: char) {
+        if self.typed_words.len() <= self.word_index {
+            self.typed_words.push(String::new());
+        }
+        let typed_len = self.typed_words[self.word_index].len();
+        self.typed_words[self.word_index].push(c);
+        // Combining marks join the previous character instead of moving the cursor
+        self.char_index = grapheme_count(&self.typed_words[self.word_index]);
+        let position = self.char_index.saturating_sub(1);
+        let target = graphemes(&self.target_words[self.word_index]);
+        let expected = target.get(position).map(|g| g.to_string());
+        let correct =
+            graphemes(&self.typed_words[self.word_index]).get(position) == target.get(position);
+        self.record_key(expected.as_deref(), c, correct);
+        if self.reject_mistake(typed_len) {
+            return;
+        }
+        if self.error_mode == ErrorMode::SuddenDeath
+            && !self.target_words[self.word_index].starts_with(&self.typed_words[self.word_index])
+        {
+            self.fail_round();
+            return;
+        }
+        // Last word of the text finishes the round without a trailing space
+        if self.text_fully_loaded()
+            && self.word_index + 1 == self.target_words.len()
+            && self.typed_words[self.word_index] == self.target_words[self.word_index]
+        {
+            self.next_word();
+        }
+    }
+    /// Undoes input that strays from the target in strict mode, true when rejected
+    fn reject_mistake(&mut self, typed_len: usize) -> bool {
+        if self.error_mode != ErrorMode::Strict
+            || self.target_words[self.word_index].starts_with(&self.typed_words[self.word_index])
+        {
+            return false;
+        }
+        self.typed_words[self.word_index].truncate(typed_len);
+        self.char_index = grapheme_count(&self.typed_words[self.word_index]);
+        self.rejected_keys += 1;
+        true
+    }
     /// Handles key press logic
     fn handle_key_event(&mut self, key_event: KeyEvent) {
         use crossterm::event::KeyModifiers;
         // Screen state specific logic
         match self.current_screen {
             CurrentScreen::Main => {
+                let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+                // Ctrl + p pauses or resumes, other keys except quit are ignored while paused
+                match key_event.code {
+                    KeyCode::Char('p') if ctrl => {
+                        match self.paused_at {
+                            Some(_) => self.resume(),
+                            None => self.pause(),
+                        }
+                        return;
+                    }
+                    // Ctrl + r restarts with new words, Ctrl + t with the same words
+                    KeyCode::Char('r') if ctrl => {
+                        self.restart(false);
+                        return;
+                    }
+                    KeyCode::Char('t') if ctrl => {
+                        self.restart(true);
+                        return;
+                    }
+                    // Esc drops the round without saving it and opens the options
+                    KeyCode::Esc => {
+                        self.restart(false);
+                        self.abandoned = true;
+                        self.current_screen = CurrentScreen::ShowOptions;
+                        return;
+                    }
+                    _ => {}
+                }
+                if self.paused_at.is_some() {
+                    self.exit = key_event.code == KeyCode::Char('c') && ctrl;
+                    return;
+                }
                 // Start countdown
                 if self.start_time.is_none() {
                     self.start_time = Some(Instant::now());
@@ -797,28 +1555,58 @@ impl App {
                     KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
                         self.exit = true;
                     }
-                    KeyCode::Char(' ') => {
-                        if self.char_index > 0 {
-                            self.next_word();
+                    // Ctrl + e ends a zen round once something has been typed
+                    KeyCode::Char('e')
+                        if self.zen && key_event.modifiers.contains(KeyModifiers::CONTROL) =>
+                    {
+                        if self.word_index == 0 && self.char_index == 0 {
+                            return;
                         }
+                        self.finish_round();
                     }
-                    KeyCode::Char(c) => {
-                        if self.typed_words.len() <= self.word_index {
-                            self.typed_words.push(String::new());
+                    // A space at the start of a word is ignored, code types it like any character
+                    KeyCode::Char(' ') => {
+                        if self.text_source == TextSource::Code {
+                            self.type_char(' ');
+                        } else if self.char_index > 0 {
+                            self.submit_word(' ');
                         }
-                        self.typed_words[self.word_index].push(c);
-                        self.char_index += 1;
                     }
+                    // Code lines end with enter
+                    KeyCode::Enter
+                        if self.text_source == TextSource::Code && self.char_index > 0 =>
+                    {
+                        self.submit_word('\n');
+                    }
+                    // Tab inserts spaces up to the next tab stop
+                    KeyCode::Tab if self.text_source == TextSource::Code => {
+                        let spaces = TAB_WIDTH - self.char_index % TAB_WIDTH;
+                        let target = graphemes(&self.target_words[self.word_index]);
+                        let correct = (self.char_index..self.char_index + spaces)
+                            .all(|i| target.get(i) == Some(&" "));
+                        self.record_key(None, '\t', correct);
+                        let typed_len = self.typed_words[self.word_index].len();
+                        self.typed_words[self.word_index].push_str(&" ".repeat(spaces));
+                        self.char_index += spaces;
+                        self.reject_mistake(typed_len);
+                    }
+                    KeyCode::Char(c) => self.type_char(c),
                     KeyCode::Backspace => {
+                        self.last_key_ms = Some(self.elapsed().as_millis() as u64);
                         if self.char_index > 0 {
-                            self.char_index -= 1;
-                            self.typed_words[self.word_index].pop();
+                            pop_grapheme(&mut self.typed_words[self.word_index]);
+                            self.char_index = grapheme_count(&self.typed_words[self.word_index]);
                         } else {
                             self.prev_word()
                         }
                     }
                     _ => {}
                 }
+                if let CurrentScreen::Main = self.current_screen
+                    && self.start_time.is_some()
+                {
+                    self.record_keystroke(self.elapsed());
+                }
             }
             CurrentScreen::EndRound => {
                 // Start cooldown for user input
@@ -834,14 +1622,32 @@ impl App {
                                 self.exit = true;
                             }
                             // r to restart game
-                            KeyCode::Char('r') => {
-                                *self = App::new_with_config(self.config.clone()) // new app with custom config
-                            }
+                            KeyCode::Char('r') => self.restart(false),
+                            // k to switch what the keyboard heatmap shows
+                            KeyCode::Char('k') => self.heatmap_view = (self.heatmap_view + 1) % 4,
                             // e to edit config
                             KeyCode::Char('e') => {
                                 self.cooldown_start = None;
                                 self.current_screen = CurrentScreen::ShowOptions;
                             }
+                            // b to view book progress
+                            KeyCode::Char('b') => {
+                                self.cooldown_start = None;
+                                self.load_books();
+                                self.current_screen = CurrentScreen::Books;
+                            }
+                            // d to view the daily challenge
+                            KeyCode::Char('d') => {
+                                self.cooldown_start = None;
+                                self.daily_results = daily::load_daily();
+                                self.current_screen = CurrentScreen::Daily;
+                            }
+                            // h to view round history
+                            KeyCode::Char('h') => {
+                                self.cooldown_start = None;
+                                self.history = history::load_history();
+                                self.current_screen = CurrentScreen::History;
+                            }
                             _ => {}
                         }
                     } else {
@@ -852,106 +1658,243 @@ impl App {
                     }
                 }
             }
-            CurrentScreen::ShowOptions => match key_event.code {
-                // Switch between options with right arrow or l
-                KeyCode::Right | KeyCode::Char('l') => match self.config.choice_index {
-                    0 => match self.config.round_time_index {
-                        2 => {
-                            self.config.round_time_index = 0;
-                        }
-                        _ => {
-                            self.config.round_time_index += 1;
-                        }
-                    },
-                    1 => match self.config.text_theme_index {
-                        3 => {
-                            self.config.text_theme_index = 0;
-                        }
-                        _ => {
-                            self.config.text_theme_index += 1;
-                        }
-                    },
-                    _ => {}
-                },
-                // Switch between options with left arrow or h
-                KeyCode::Left | KeyCode::Char('h') => match self.config.choice_index {
-                    0 => match self.config.round_time_index {
-                        0 => {
-                            self.config.round_time_index = 2;
-                        }
-                        _ => {
-                            self.config.round_time_index -= 1;
-                        }
-                    },
-                    1 => match self.config.text_theme_index {
-                        0 => {
-                            self.config.text_theme_index = 3;
-                        }
-                        _ => {
-                            self.config.text_theme_index -= 1;
-                        }
-                    },
-                    _ => {}
-                },
-                // Move down with arrow or j
-                KeyCode::Down | KeyCode::Char('j') => match self.config.choice_index {
-                    0 => {
-                        self.config.choice_index = 1;
-                    }
-                    1 => {
-                        self.config.choice_index = 2;
-                    }
-                    2 => {
-                        self.config.choice_index = 0;
+            CurrentScreen::Daily => match key_event.code {
+                KeyCode::Enter => *self = App::new_daily(self.config.clone()),
+                KeyCode::Esc | KeyCode::Char('d') => self.current_screen = CurrentScreen::EndRound,
+                _ => {}
+            },
+            CurrentScreen::History => {
+                if let KeyCode::Esc | KeyCode::Char('h') = key_event.code {
+                    self.current_screen = CurrentScreen::EndRound;
+                }
+            }
+            CurrentScreen::Books => match key_event.code {
+                KeyCode::Down | KeyCode::Char('j') if !self.books.is_empty() => {
+                    self.book_choice = (self.book_choice + 1) % self.books.len();
+                }
+                KeyCode::Up | KeyCode::Char('k') if !self.books.is_empty() => {
+                    self.book_choice = match self.book_choice {
+                        0 => self.books.len() - 1,
+                        i => i - 1,
+                    };
+                }
+                // Enter switches to the selected book and starts typing it
+                KeyCode::Enter if !self.books.is_empty() => {
+                    self.config.text_source_index = 3;
+                    self.config.book_index = self.book_choice;
+                    self.config.save();
+                    *self = App::new_with_config(self.config.clone(), None);
+                    self.clamp_choice();
+                }
+                KeyCode::Esc | KeyCode::Char('b') => self.current_screen = CurrentScreen::EndRound,
+                _ => {}
+            },
+            CurrentScreen::ShowOptions => {
+                let rows = self.option_rows();
+                let save_index = rows.len() - 1;
+                let row = rows[self.config.choice_index];
+                let typed_number = matches!(
+                    row,
+                    OptionRow::Seed | OptionRow::WordCount | OptionRow::RoundTime | OptionRow::Pace
+                );
+                match key_event.code {
+                    // Seed, custom time and custom word count are typed in directly
+                    KeyCode::Char(c) if typed_number && c.is_ascii_digit() => {
+                        self.edit_option_number(row, Some(c));
                     }
-                    _ => {}
-                },
-                // Move up with arrow or k
-                KeyCode::Up | KeyCode::Char('k') => match self.config.choice_index {
-                    0 => {
-                        self.config.choice_index = 2;
+                    KeyCode::Backspace if typed_number => self.edit_option_number(row, None),
+                    // Switch between options with right arrow or l
+                    KeyCode::Right | KeyCode::Char('l') => self.cycle_option(true),
+                    // Switch between options with left arrow or h
+                    KeyCode::Left | KeyCode::Char('h') => self.cycle_option(false),
+                    // Move down with arrow or j
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        self.config.choice_index =
+                            (self.config.choice_index + 1) % (save_index + 1);
                     }
-                    1 => {
-                        self.config.choice_index = 0;
+                    // Move up with arrow or k
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        self.config.choice_index = match self.config.choice_index {
+                            0 => save_index,
+                            i => i - 1,
+                        };
                     }
-                    2 => {
-                        self.config.choice_index = 1;
+                    // Enter to save and exit options screen
+                    KeyCode::Enter => {
+                        if self.config.choice_index == save_index {
+                            self.config.save();
+                            // An abandoned round has no results to go back to
+                            match self.abandoned {
+                                true => self.restart(false),
+                                false => self.current_screen = CurrentScreen::EndRound,
+                            }
+                        } else {
+                            self.config.choice_index = save_index;
+                        }
                     }
                     _ => {}
-                },
-                // Enter to save and exit options screen
-                KeyCode::Enter => match self.config.choice_index {
-                    2 => self.current_screen = CurrentScreen::EndRound,
-                    _ => self.config.choice_index = 2,
-                },
-                _ => {}
-            },
+                }
+            }
+        }
+    }
+    /// Starts a fresh round with the current config, nothing from this one is saved
+    fn restart(&mut self, same_words: bool) {
+        self.forfeit_daily();
+        // A text file starts over from the top
+        let text_file = self.text_file.take();
+        *self = match (self.daily, same_words) {
+            // Daily retries are practice on the same words, new words use the saved config
+            (Some(_), true) => App::new_daily(self.config.clone()),
+            (None, true) => App::build(self.config.clone(), text_file, Some(self.seed)),
+            (_, false) => App::new_with_config(self.config.clone(), text_file),
         }
     }
     /// Used to go to next word on screen during game
     fn next_word(&mut self) {
+        // Submitting a wrong word also ends a sudden death round
+        if self.error_mode == ErrorMode::SuddenDeath
+            && self.typed_words[self.word_index] != self.target_words[self.word_index]
+        {
+            self.fail_round();
+            return;
+        }
+        // Strict mode keeps the cursor on a word until it is typed exactly
+        if self.error_mode == ErrorMode::Strict
+            && self.typed_words[self.word_index] != self.target_words[self.word_index]
+        {
+            self.rejected_keys += 1;
+            return;
+        }
+        // Survival takes a life for every wrong word
+        if let Some(survival) = &mut self.survival
+            && self.typed_words[self.word_index] != self.target_words[self.word_index]
+        {
+            survival.lose_life();
+        }
+        self.advance_word();
+    }
+    /// Moves the cursor to the start of the next word
+    fn advance_word(&mut self) {
+        let minute = (self.elapsed().as_secs() / 60) as usize;
+        self.word_minutes.push(minute);
+        self.update_split(self.word_index, minute, true);
+        if let Some(survival) = &mut self.survival {
+            survival.next_word();
+        }
         self.word_index += 1;
         self.char_index = 0;
         if self.typed_words.len() <= self.word_index {
             self.typed_words.push(String::new());
         }
-        if self.typed_words.len() > self.target_words.len() - 20 {
+        // Word count rounds load every word up front
+        if self.word_goal.is_none()
+            && self.typed_words.len() > self.target_words.len().saturating_sub(20)
+        {
             self.extend_lines();
         }
+        self.prefill_indent();
+    }
+    /// True for survival words skipped when their timer ran out
+    fn timed_out(&self, word: usize) -> bool {
+        self.survival
+            .as_ref()
+            .is_some_and(|s| s.timed_out.contains(&word))
+    }
+    /// Adds a finished word to the split for its minute, or takes it back out
+    fn update_split(&mut self, word: usize, minute: usize, add: bool) {
+        let (typed, target) = self.word_graphemes(word);
+        let stats = CharStats::word(&typed, &target, !self.timed_out(word));
+        if self.splits.len() <= minute {
+            self.splits.resize(minute + 1, CharStats::default());
+        }
+        match add {
+            true => self.splits[minute] += stats,
+            false => self.splits[minute] -= stats,
+        }
     }
     /// Used to go to prev word on screen during game
     fn prev_word(&mut self) {
-        if self.word_index > 0 {
+        // Survival words are gone once their timer moves on
+        if self.word_index > 0 && self.survival.is_none() {
+            // The word is typed again so it leaves the split it was counted in
+            if let Some(minute) = self.word_minutes.pop() {
+                self.update_split(self.word_index - 1, minute, false);
+            }
             self.typed_words.pop();
             self.word_index -= 1;
-            self.char_index = self.typed_words[self.word_index].len();
+            self.char_index = grapheme_count(&self.typed_words[self.word_index]);
         }
     }
     /// Used to add new lines to screen dynamically
     fn extend_lines(&mut self) {
-        let word_list = self.text_theme.word_list();
-        let extension_words = generate_words(&word_list, 30);
-        self.target_words.extend(extension_words);
+        match self.text_source {
+            TextSource::Words => {
+                let word_list = self.text_theme.word_list();
+                let extension_words =
+                    generate_words(&word_list, self.text_theme.is_weighted(), 30, &mut self.rng);
+                let extension_words = self.modifiers.apply(
+                    extension_words,
+                    self.target_words.last().map(String::as_str),
+                    &mut self.rng,
+                );
+                self.target_words.extend(extension_words);
+            }
+            // Quote rounds end with the last passage
+            TextSource::Quotes => {}
+            // Files feed the next chunk in order until they run out
+            TextSource::Book => {
+                if let Some(book) = self.active_book.map(|i| &self.books[i]) {
+                    let chunk = book.chunk(
+                        self.book_start + self.target_words.len(),
+                        text_file::NEXT_CHUNK_WORDS,
+                    );
+                    self.target_words.extend(chunk);
+                }
+            }
+            // Prose never runs out, keep adding sentences
+            TextSource::Prose => {
+                if let Some(model) = &self.markov {
+                    let sentences = model.generate(30, &mut self.rng);
+                    self.target_words.extend(sentences);
+                }
+            }
+            TextSource::File => {
+                if let Some(file) = &self.text_file {
+                    let chunk = file.chunk(self.target_words.len(), text_file::NEXT_CHUNK_WORDS);
+                    self.target_words.extend(chunk);
+                }
+            }
+            TextSource::Code => {
+                let snippet = code::snippet_lines(self.code_language, &mut self.rng);
+                self.target_words.extend(snippet);
+            }
+        }
+    }
+    /// Loads exactly the words needed for a word count round
+    fn fill_word_goal(&mut self) {
+        let Some(goal) = self.word_goal else {
+            return;
+        };
+        while self.target_words.len() < goal {
+            let loaded = self.target_words.len();
+            self.extend_lines();
+            // Quotes, books and files can run out before the goal
+            if self.target_words.len() == loaded {
+                break;
+            }
+        }
+        self.target_words.truncate(goal);
+    }
+    /// Types the leading indentation of a new code line for the user
+    fn prefill_indent(&mut self) {
+        if !self.skip_indent || self.char_index > 0 {
+            return;
+        }
+        if let Some(target) = self.target_words.get(self.word_index) {
+            let indent: String = target.chars().take_while(|c| c.is_whitespace()).collect();
+            self.char_index = grapheme_count(&indent);
+            self.typed_words[self.word_index] = indent;
+        }
     }
     /// Render main app
     fn render_main(&self, area: Rect, buf: &mut Buffer) {
@@ -1005,34 +1948,147 @@ impl App {
             .split(inner_layout[1]);
         // Outer layer content
         let title = Line::from(vec![Span::styled(
-            "  Type To Begin  ",
+            match self.paused_at {
+                Some(_) => "  Paused  ",
+                None => "  Type To Begin  ",
+            },
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         )]);
-        let instructions = Line::from(vec![
-            Span::raw(" Time Remaining: "),
+        // Word count and zen rounds count up instead of down
+        let (time_label, time) = match self.is_timed() {
+            true => (" Time Remaining: ", self.time_remaining),
+            false => (" Time: ", self.elapsed().as_secs()),
+        };
+        let words_typed = match self.word_goal {
+            Some(_) => format!("{}/{}", self.word_index, self.target_words.len()),
+            None => self.word_index.to_string(),
+        };
+        let mut instructions = Line::from(vec![
+            Span::raw(time_label),
             Span::styled(
-                self.time_remaining.to_string(),
+                time.to_string(),
                 Style::default()
                     .fg(Color::Yellow)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw("  |  Words Typed: "),
             Span::styled(
-                self.word_index.to_string(),
+                words_typed,
                 Style::default()
                     .fg(Color::Green)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::raw("  |  Quit: "),
-            Span::styled(
-                " <Ctrl + C> ",
+        ]);
+        if let Some(survival) = &self.survival {
+            let level = Survival::level(self.word_index);
+            let lives = "♥".repeat(survival.lives)
+                + &"♡".repeat(survival::SURVIVAL_LIVES.saturating_sub(survival.lives));
+            // The word timer stands still while paused
+            let paused_now = self.paused_at.map_or(Duration::ZERO, |p| p.elapsed());
+            let time_left = survival
+                .time_left(level, paused_now)
+                .unwrap_or_else(|| Survival::budget(level));
+            instructions.push_span(Span::raw("  |  Lives: "));
+            instructions.push_span(Span::styled(
+                lives,
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+            instructions.push_span(Span::raw("  |  Level: "));
+            instructions.push_span(Span::styled(
+                level.to_string(),
                 Style::default()
                     .fg(Color::Yellow)
                     .add_modifier(Modifier::BOLD),
-            ),
-        ]);
+            ));
+            instructions.push_span(Span::raw("  |  Word: "));
+            instructions.push_span(Span::styled(
+                format!("{:.1}s", time_left.as_secs_f64()),
+                Style::default()
+                    .fg(if time_left.as_millis() < 1000 {
+                        Color::Red
+                    } else {
+                        Color::Green
+                    })
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        // Characters ahead of or behind the pace caret
+        if let Some(lead) = self.start_time.and_then(|_| self.pace_lead(self.elapsed())) {
+            instructions.push_span(Span::raw("  |  Pace: "));
+            instructions.push_span(Span::styled(
+                format!("{:+.0}", lead),
+                Style::default()
+                    .fg(if lead >= 0.0 {
+                        Color::Green
+                    } else {
+                        Color::Red
+                    })
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if let Some(lead) = self
+            .start_time
+            .and_then(|_| self.ghost_lead(self.elapsed()))
+        {
+            instructions.push_span(Span::raw("  |  Ghost: "));
+            instructions.push_span(Span::styled(
+                format!("{:+}", lead),
+                Style::default()
+                    .fg(if lead >= 0 { Color::Green } else { Color::Red })
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if self.error_mode == ErrorMode::Strict {
+            instructions.push_span(Span::raw("  |  Rejected: "));
+            instructions.push_span(Span::styled(
+                self.rejected_keys.to_string(),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+        }
+        if self.zen {
+            instructions.push_span(Span::raw("  |  End: "));
+            instructions.push_span(Span::styled(
+                " <Ctrl + E> ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if self.start_time.is_some() {
+            instructions.push_span(Span::raw(match self.paused_at {
+                Some(_) => "  |  Resume: ",
+                None => "  |  Pause: ",
+            }));
+            instructions.push_span(Span::styled(
+                " <Ctrl + P> ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        instructions.push_span(Span::raw("  |  Restart: "));
+        instructions.push_span(Span::styled(
+            " <Ctrl + R/T> ",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+        instructions.push_span(Span::raw("  |  Menu: "));
+        instructions.push_span(Span::styled(
+            " <Esc> ",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+        instructions.push_span(Span::raw("  |  Quit: "));
+        instructions.push_span(Span::styled(
+            " <Ctrl + C> ",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
         let outer_block = Block::bordered()
             .title(title.centered())
             .title_bottom(instructions.centered())
@@ -1043,34 +2099,87 @@ impl App {
         let mut current_line = Vec::new(); // current line state
         let mut current_width = 0;
         let max_width = main_content_layout[1].width as usize; // determine line width
+        let code_mode = self.text_source == TextSource::Code;
+        let mut cursor_line = 0;
+
+        // Pace caret only moves once the round has started
+        let pace = self
+            .start_time
+            .and_then(|_| self.pace_position(self.elapsed()));
+        let pace_style = |style: Style| style.bg(Color::Magenta);
+        let ghost = self
+            .start_time
+            .and_then(|_| self.ghost_position(self.elapsed()));
+        let ghost_style = |style: Style| style.bg(Color::Cyan);
 
         // Iterare over words and output to main screen buffer
         for (i, word) in self.target_words.iter().enumerate() {
             let mut word_spans = vec![];
             let mut word_width = 0;
 
-            for (j, c) in word.chars().enumerate() {
+            let typed = self.typed_words.get(i).map(|w| graphemes(w));
+            for (j, g) in graphemes(word).into_iter().enumerate() {
                 let style = if i == self.word_index && j == self.char_index {
                     // Current index highlighted
                     Style::default()
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::UNDERLINED)
                 } else {
-                    let typed_char = self.typed_words.get(i).and_then(|w| w.chars().nth(j));
+                    let typed_char = typed.as_ref().and_then(|t| t.get(j));
                     match typed_char {
-                        Some(tc) if tc == c => Style::default().fg(Color::White), // correct
-                        Some(_) => Style::default().fg(Color::Red),               // incorrect
-                        None => Style::default().fg(Color::DarkGray),             // not typed
+                        Some(tc) if *tc == g => Style::default().fg(Color::White), // correct
+                        Some(_) if g == " " => Style::default().bg(Color::Red),    // missed space
+                        Some(_) => Style::default().fg(Color::Red),                // incorrect
+                        None => Style::default().fg(Color::DarkGray),              // not typed
                     }
                 };
-                let span = Span::styled(c.to_string(), style);
+                let style = if ghost == Some((i, j)) {
+                    ghost_style(style)
+                } else if pace == Some((i, j)) {
+                    pace_style(style)
+                } else {
+                    style
+                };
+                let span = Span::styled(g, style);
                 word_width += span.content.width();
                 word_spans.push(span);
             }
-            word_spans.push(Span::raw(" ")); // space after each word
+            // Code keeps each target line on its own screen line
+            if code_mode {
+                let enter_style = if i == self.word_index && self.char_index >= grapheme_count(word)
+                {
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::UNDERLINED)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                let enter_style = if ghost == Some((i, grapheme_count(word))) {
+                    ghost_style(enter_style)
+                } else if pace == Some((i, grapheme_count(word))) {
+                    pace_style(enter_style)
+                } else {
+                    enter_style
+                };
+                word_spans.push(Span::styled("↵", enter_style));
+                if i == self.word_index {
+                    cursor_line = lines.len();
+                }
+                lines.push(Line::from(word_spans));
+                continue;
+            }
+            // space after each word
+            if ghost == Some((i, grapheme_count(word))) {
+                word_spans.push(Span::styled(" ", ghost_style(Style::default())));
+            } else if pace == Some((i, grapheme_count(word))) {
+                word_spans.push(Span::styled(" ", pace_style(Style::default())));
+            } else {
+                word_spans.push(Span::raw(" "));
+            }
             word_width += 1;
 
-            if current_width + word_width > max_width {
+            // Wrap whole words, punctuation stays with its word
+            if current_width + word_width > max_width && !current_line.is_empty() {
                 lines.push(Line::from(current_line));
                 current_line = vec![];
                 current_width = 0;
@@ -1083,6 +2192,14 @@ impl App {
             current_line.extend(word_spans);
             current_width += word_width;
         }
+        // Scroll code so the current line stays near the top
+        if code_mode {
+            lines = lines
+                .into_iter()
+                .skip(cursor_line.saturating_sub(2))
+                .take(8)
+                .collect();
+        }
         // Blank outer template
         let outer_paragraph = Paragraph::new(Text::from(""))
             .block(outer_block)
@@ -1090,6 +2207,17 @@ impl App {
         outer_paragraph.render(padding_width[1], buf);
         // Title paragraph top of app
         title_paragraph.render(outer_layout[0], buf);
+        // Text is hidden while paused so the round can't be read ahead
+        if self.paused_at.is_some() {
+            lines = vec![
+                Line::from(""),
+                Line::from(Span::styled(
+                    "Round paused, press <Ctrl + P> to resume",
+                    Style::default().fg(Color::DarkGray),
+                ))
+                .centered(),
+            ];
+        }
         // Main content for game
         let main_paragraph = Paragraph::new(Text::from(lines))
             .block(main_content_block)
@@ -1120,33 +2248,46 @@ impl App {
             .direction(Direction::Vertical)
             .constraints(vec![Constraint::Percentage(70), Constraint::Percentage(30)])
             .split(padding_width[1]);
+        // The speed chart sits between the summary and the leaderboard once there is a line to draw
+        let show_chart = self.samples.len() > 1;
         let inner_layout = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .constraints(match show_chart {
+                true => vec![
+                    Constraint::Percentage(35),
+                    Constraint::Percentage(35),
+                    Constraint::Percentage(30),
+                ],
+                false => vec![Constraint::Percentage(50), Constraint::Percentage(50)],
+            })
             .split(outer_layout[0]);
+        let right_area = inner_layout[inner_layout.len() - 1];
         // Get statistics for output
-        let round_type = match self.round_time {
-            RoundTime::Default => "30s round".to_string(),
-            RoundTime::Min => "1 min round".to_string(),
-            RoundTime::TwoMin => "2 min round".to_string(),
+        let mut round_type = match self.word_goal {
+            Some(goal) => format!("{} word round", goal),
+            None if self.zen => "zen round".to_string(),
+            None if self.daily.is_some() => match self.daily_official {
+                true => "daily challenge (official)".to_string(),
+                false => "daily challenge (practice)".to_string(),
+            },
+            None if self.survival.is_some() => format!(
+                "survival round (level {})",
+                Survival::level(self.word_index)
+            ),
+            None => format!("{} round", duration_label(self.round_time)),
         };
+        match self.text_source {
+            TextSource::Words => {}
+            TextSource::Quotes => round_type.push_str(" (quotes)"),
+            TextSource::Code => round_type.push_str(" (code)"),
+            TextSource::Book => round_type.push_str(" (book)"),
+            TextSource::Prose => round_type.push_str(" (prose)"),
+            TextSource::File => round_type.push_str(" (file)"),
+        }
+        if self.was_paused {
+            round_type.push_str(", paused");
+        }
         let round_results = self.get_accuracy();
-        let actual_wpm = match self.round_time {
-            RoundTime::Default => {
-                (self.word_index - (self.word_index - round_results.correct_words)) as f64 / 0.5
-            }
-            RoundTime::Min => {
-                (self.word_index - (self.word_index - round_results.correct_words)) as f64
-            }
-            RoundTime::TwoMin => {
-                (self.word_index - (self.word_index - round_results.correct_words)) as f64 / 2.0
-            }
-        };
-        let raw_wpm = match self.round_time {
-            RoundTime::Default => self.word_index as f64 / 0.5,
-            RoundTime::Min => self.word_index as f64,
-            RoundTime::TwoMin => self.word_index as f64 / 2.0,
-        };
         // Top left block for round stats
         let top_left_title = Line::from(vec![Span::styled(
             " Round Summary ",
@@ -1156,17 +2297,17 @@ impl App {
             .title(top_left_title)
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded);
-        let user_stats = Text::from(vec![
+        let mut user_stats = Text::from(vec![
             Line::from(vec![Span::raw("")]),
             Line::from(vec![
                 Span::styled(
-                    format!("WPM: "),
+                    "WPM: ".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!("{}", actual_wpm),
+                    format!("{:.0}", round_results.wpm.net),
                     Style::default()
                         .fg(Color::Green)
                         .add_modifier(Modifier::BOLD),
@@ -1175,13 +2316,28 @@ impl App {
             .centered(),
             Line::from(vec![
                 Span::styled(
-                    format!("RAW WPM: "),
+                    "RAW WPM: ".to_string(),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{:.0}", round_results.wpm.raw),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ])
+            .centered(),
+            Line::from(vec![
+                Span::styled(
+                    "ADJUSTED WPM: ".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!("{}", raw_wpm),
+                    format!("{:.0}", round_results.wpm.adjusted),
                     Style::default()
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::BOLD),
@@ -1190,7 +2346,7 @@ impl App {
             .centered(),
             Line::from(vec![
                 Span::styled(
-                    format!("WORD ACCURACY: "),
+                    "WORD ACCURACY: ".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
@@ -1205,13 +2361,13 @@ impl App {
             .centered(),
             Line::from(vec![
                 Span::styled(
-                    format!("CHAR ACCURACY: "),
+                    "CHAR ACCURACY: ".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!("{:.1} %", round_results.percentage_chars),
+                    format!("{:.1} %", round_results.chars.accuracy()),
                     Style::default()
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::BOLD),
@@ -1220,7 +2376,7 @@ impl App {
             .centered(),
             Line::from(vec![
                 Span::styled(
-                    format!("WORDS TYPED: "),
+                    "WORDS TYPED: ".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
@@ -1235,7 +2391,7 @@ impl App {
             .centered(),
             Line::from(vec![
                 Span::styled(
-                    format!("WORDS CORRECT: "),
+                    "WORDS CORRECT: ".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
@@ -1250,13 +2406,13 @@ impl App {
             .centered(),
             Line::from(vec![
                 Span::styled(
-                    format!("CHARS TYPED: "),
+                    "CHARS TYPED: ".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!("{}", round_results.total_chars),
+                    format!("{}", round_results.chars.typed),
                     Style::default()
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::BOLD),
@@ -1265,13 +2421,13 @@ impl App {
             .centered(),
             Line::from(vec![
                 Span::styled(
-                    format!("CORRECT CHARS: "),
+                    "CORRECT CHARS: ".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!("{}", round_results.correct_chars),
+                    format!("{}", round_results.chars.correct),
                     Style::default()
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::BOLD),
@@ -1280,13 +2436,13 @@ impl App {
             .centered(),
             Line::from(vec![
                 Span::styled(
-                    format!("TYPE: "),
+                    "TYPE: ".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!("{}", round_type),
+                    round_type.to_string(),
                     Style::default()
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::BOLD),
@@ -1294,13 +2450,227 @@ impl App {
             ])
             .centered(),
         ]);
+        // Files and books show how far through the text the user got, other sources the seed to replay them
+        let book = self.active_book.map(|i| &self.books[i]);
+        let (extra_label, extra_value) = match (&self.text_file, book) {
+            (_, Some(book)) => (
+                "PROGRESS: ",
+                format!(
+                    "{}, {} ({:.1} % of book)",
+                    book.title,
+                    book.chapters[book.chapter_at(book.position)].title,
+                    book.percent_complete()
+                ),
+            ),
+            (Some(file), _) => (
+                "PROGRESS: ",
+                format!(
+                    "{}/{} words of {} ({:.1} %)",
+                    self.word_index.min(file.words.len()),
+                    file.words.len(),
+                    file.name,
+                    self.word_index.min(file.words.len()) as f64 / file.words.len() as f64
+                        * 100_f64
+                ),
+            ),
+            (None, None) => ("SEED: ", self.seed.to_string()),
+        };
+        user_stats.push_line(
+            Line::from(vec![
+                Span::styled(
+                    extra_label,
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    extra_value,
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ])
+            .centered(),
+        );
+        if let Some((lead, pace)) = self.pace_lead(self.round_elapsed).zip(self.pace_wpm) {
+            let (outcome, color) = match lead >= 0.0 {
+                true => ("beat", Color::Green),
+                false => ("missed", Color::Red),
+            };
+            user_stats.push_line(
+                Line::from(vec![
+                    Span::styled(
+                        "PACE: ",
+                        Style::default()
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("{} {} WPM ({:+.0} chars)", outcome, pace, lead),
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    ),
+                ])
+                .centered(),
+            );
+        }
+        // Ghost result with the time gained or lost every few words
+        if let Some((ghost, lead)) = self.ghost.as_ref().zip(self.ghost_lead(self.round_elapsed)) {
+            let (outcome, color) = match lead >= 0 {
+                true => ("beat", Color::Green),
+                false => ("lost to", Color::Red),
+            };
+            user_stats.push_line(
+                Line::from(vec![
+                    Span::styled(
+                        "GHOST: ",
+                        Style::default()
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("{} {} WPM run ({:+} chars)", outcome, ghost.wpm, lead),
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    ),
+                ])
+                .centered(),
+            );
+            let mut split_spans = vec![Span::styled(
+                format!("SPLITS ({} words): ", ghost::GHOST_SPLIT_WORDS),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            )];
+            for split in ghost::splits(&self.keystrokes, ghost) {
+                split_spans.push(Span::styled(
+                    format!("{:+.1}s ", split),
+                    Style::default().fg(if split >= 0.0 {
+                        Color::Green
+                    } else {
+                        Color::Red
+                    }),
+                ));
+            }
+            user_stats.push_line(Line::from(split_spans).centered());
+        }
+        // Strict mode text is always correct, so accuracy comes from the refused keys
+        if self.error_mode == ErrorMode::Strict {
+            let accepted = round_results.chars.typed + self.word_index;
+            let key_accuracy = match accepted + self.rejected_keys {
+                0 => 100_f64,
+                keys => accepted as f64 / keys as f64 * 100_f64,
+            };
+            user_stats.push_line(
+                Line::from(vec![
+                    Span::styled(
+                        "REJECTED KEYS: ",
+                        Style::default()
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!(
+                            "{} ({:.1} % keystroke accuracy)",
+                            self.rejected_keys, key_accuracy
+                        ),
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    ),
+                ])
+                .centered(),
+            );
+        }
+        // Sudden death points out the word and character that ended the round
+        if let Some((word, at)) = self.failed_at {
+            let target = graphemes(self.target_words[word].trim_start());
+            let at = at.saturating_sub(grapheme_count(&self.target_words[word]) - target.len());
+            let mut spans = vec![Span::styled(
+                "FAILED ON: ",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            )];
+            for (i, grapheme) in target.iter().enumerate() {
+                spans.push(Span::styled(
+                    grapheme.to_string(),
+                    match i.cmp(&at) {
+                        std::cmp::Ordering::Less => Style::default().fg(Color::Green),
+                        std::cmp::Ordering::Equal => Style::default()
+                            .fg(Color::Black)
+                            .bg(Color::Red)
+                            .add_modifier(Modifier::BOLD),
+                        std::cmp::Ordering::Greater => Style::default().fg(Color::Yellow),
+                    },
+                ));
+            }
+            // Extra characters past the end of the word
+            if at >= target.len() {
+                spans.push(Span::styled(" ", Style::default().bg(Color::Red)));
+            }
+            spans.push(Span::styled(
+                format!("  (typed '{}')", self.typed_words[word].trim_start()),
+                Style::default().fg(Color::DarkGray),
+            ));
+            user_stats.push_line(Line::from(spans).centered());
+        }
+        if self.text_source == TextSource::Code {
+            user_stats.push_line(
+                Line::from(vec![
+                    Span::styled(
+                        "SYMBOL ACCURACY: ",
+                        Style::default()
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!(
+                            "{:.1} % ({}/{})",
+                            round_results.percentage_symbols,
+                            round_results.correct_symbols,
+                            round_results.total_symbols
+                        ),
+                        Style::default()
+                            .fg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                ])
+                .centered(),
+            );
+        }
+        // Attribution for each quote the user reached
+        for (start, quote) in self.passages.iter() {
+            if *start > self.word_index {
+                break;
+            }
+            user_stats.push_line(
+                Line::from(vec![
+                    Span::styled(
+                        "SOURCE: ",
+                        Style::default()
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        quote.source,
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::ITALIC),
+                    ),
+                ])
+                .centered(),
+            );
+        }
         let stats_paragraph = Paragraph::new(user_stats)
             .block(top_left_block)
             .alignment(Alignment::Center);
         stats_paragraph.render(inner_layout[0], buf);
+        if show_chart {
+            self.render_wpm_chart(inner_layout[1], buf);
+        }
         // Top right block for leaderboard
         let top_right_title = Line::from(vec![Span::styled(
-            " Leaderboard ",
+            match self.survival {
+                Some(_) => format!(" Survival High Scores ({}) ", self.leaderboard_category()),
+                None => format!(" Leaderboard ({}) ", self.leaderboard_category()),
+            },
             Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
         )]);
         let top_right_block = Block::default()
@@ -1309,7 +2679,8 @@ impl App {
             .border_type(BorderType::Rounded);
         let mut leaderboard_lines: Vec<Line> = Vec::<Line>::new();
         leaderboard_lines.push(Line::from(vec![Span::raw("")]));
-        if let Some(scores) = &self.top_scores {
+        let scores = self.category_scores();
+        if !scores.is_empty() {
             for (i, score) in scores.iter().enumerate() {
                 let line = Line::from(vec![
                     Span::styled(
@@ -1325,15 +2696,38 @@ impl App {
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::styled(
-                        format!("{} WPM", score.wpm_score),
+                        match self.ranks_by_words() {
+                            true => format!("{} words", score.wpm_score),
+                            false => format!("{} WPM", score.wpm_score),
+                        },
                         Style::default()
                             .fg(Color::Yellow)
                             .add_modifier(Modifier::BOLD),
                     ),
+                    Span::styled(
+                        match score.paused {
+                            true => "  (paused)",
+                            false => "",
+                        },
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(
+                        score
+                            .seed
+                            .map(|seed| format!("  #{}", seed))
+                            .unwrap_or_default(),
+                        Style::default().fg(Color::DarkGray),
+                    ),
                 ])
                 .centered();
                 leaderboard_lines.push(line);
             }
+        } else if self.zen {
+            let line = Line::from("Zen rounds are kept in history only").centered();
+            leaderboard_lines.push(line);
+        } else if self.daily.is_some() {
+            let line = Line::from("Press 'd' for daily results and your streak").centered();
+            leaderboard_lines.push(line);
         } else {
             let line = Line::from("No scores recorded").centered();
             leaderboard_lines.push(line);
@@ -1341,7 +2735,18 @@ impl App {
         let leaderboard_paragraph = Paragraph::new(Text::from(leaderboard_lines))
             .block(top_right_block)
             .alignment(Alignment::Center);
-        leaderboard_paragraph.render(inner_layout[1], buf);
+        // Rounds over a minute long also get a per minute breakdown under the leaderboard
+        match self.splits.len() > 1 {
+            true => {
+                let right_layout = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints(vec![Constraint::Percentage(40), Constraint::Percentage(60)])
+                    .split(right_area);
+                leaderboard_paragraph.render(right_layout[0], buf);
+                self.render_splits(right_layout[1], buf);
+            }
+            false => leaderboard_paragraph.render(right_area, buf),
+        }
         // Bottom block for user options
         let bottom_title = Line::from(vec![Span::styled(
             " User Options ",
@@ -1355,19 +2760,82 @@ impl App {
             Line::from(vec![Span::raw("")]),
             Line::from(vec![
                 Span::styled(
-                    format!("Press "),
+                    "Press ".to_string(),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    "'r'".to_string(),
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    " to play again".to_string(),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ])
+            .centered(),
+            Line::from(vec![
+                Span::styled(
+                    "Press ".to_string(),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    "'e'".to_string(),
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    " to edit user config".to_string(),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ])
+            .centered(),
+            Line::from(vec![
+                Span::styled(
+                    "Press ".to_string(),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    "'b'".to_string(),
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    " to view books".to_string(),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ])
+            .centered(),
+            Line::from(vec![
+                Span::styled(
+                    "Press ".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!("'r'"),
+                    "'h'".to_string(),
                     Style::default()
                         .fg(Color::Green)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!(" to play again"),
+                    " to view round history".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
@@ -1376,19 +2844,19 @@ impl App {
             .centered(),
             Line::from(vec![
                 Span::styled(
-                    format!("Press "),
+                    "Press ".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!("'e'"),
+                    "'d'".to_string(),
                     Style::default()
                         .fg(Color::Green)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!(" to edit user config"),
+                    " for the daily challenge".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
@@ -1397,19 +2865,19 @@ impl App {
             .centered(),
             Line::from(vec![
                 Span::styled(
-                    format!("Press "),
+                    "Press ".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!("'q'"),
+                    "'q'".to_string(),
                     Style::default()
                         .fg(Color::Green)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!(" to quit terminal"),
+                    " to quit terminal".to_string(),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
@@ -1420,7 +2888,109 @@ impl App {
         let bottom_paragraph = Paragraph::new(user_options)
             .block(bottom_block)
             .alignment(Alignment::Center);
-        bottom_paragraph.render(outer_layout[1], buf);
+        // Keyboard heatmap next to the options once keys have been pressed
+        match self.key_stats.keys.is_empty() {
+            true => bottom_paragraph.render(outer_layout[1], buf),
+            false => {
+                let bottom_layout = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .split(outer_layout[1]);
+                bottom_paragraph.render(bottom_layout[0], buf);
+                self.render_keyboard(bottom_layout[1], buf);
+            }
+        }
+    }
+    /// Render the keyboard coloured by error rate or latency, for this round or all rounds
+    fn render_keyboard(&self, area: Rect, buf: &mut Buffer) {
+        let (heatmap, scope) = match self.heatmap_view / 2 {
+            0 => (&self.key_stats, "this round"),
+            _ => (&self.key_totals, "all rounds"),
+        };
+        let by_latency = self.heatmap_view % 2 == 1;
+        let average = heatmap.average_latency();
+        let scale = [Color::Green, Color::Yellow, Color::LightRed, Color::Red];
+        let colour = |stats: KeyStats| {
+            let step = match by_latency {
+                // Latency is compared with the average over every key
+                true => stats
+                    .latency()
+                    .zip(average)
+                    .map(|(ms, avg)| match ms / avg {
+                        r if r < 0.85 => 0,
+                        r if r < 1.15 => 1,
+                        r if r < 1.5 => 2,
+                        _ => 3,
+                    }),
+                false => stats.error_rate().map(|rate| match rate {
+                    r if r < 0.02 => 0,
+                    r if r < 0.05 => 1,
+                    r if r < 0.1 => 2,
+                    _ => 3,
+                }),
+            };
+            step.map_or(Color::DarkGray, |i| scale[i])
+        };
+        let key_style = |stats: KeyStats| Style::default().fg(Color::Black).bg(colour(stats));
+        let mut key_lines = vec![];
+        for (i, row) in KEY_ROWS.iter().enumerate() {
+            let mut spans = vec![];
+            // Tab and enter sit at the ends of their rows like on a real keyboard
+            if i == 1 {
+                spans.push(Span::styled(" tab ", key_style(heatmap.get('\t'))));
+                spans.push(Span::raw(" "));
+            }
+            for key in row.chars() {
+                spans.push(Span::styled(
+                    format!(" {} ", key),
+                    key_style(heatmap.get(key)),
+                ));
+                spans.push(Span::raw(" "));
+            }
+            if i == 2 {
+                spans.push(Span::styled(" enter ", key_style(heatmap.get('\n'))));
+            }
+            key_lines.push(Line::from(spans).centered());
+        }
+        key_lines.push(
+            Line::from(Span::styled(
+                format!("{:^27}", "space"),
+                key_style(heatmap.get(' ')),
+            ))
+            .centered(),
+        );
+        let labels = match by_latency {
+            true => ["fast", "average", "slow", "slowest"],
+            false => ["<2%", "<5%", "<10%", "10%+"],
+        };
+        let mut legend = vec![];
+        for (label, colour) in labels.iter().zip(scale) {
+            legend.push(Span::styled("■ ", Style::default().fg(colour)));
+            legend.push(Span::raw(format!("{}  ", label)));
+        }
+        if let Some(avg) = average.filter(|_| by_latency) {
+            legend.push(Span::raw(format!("avg {:.0} ms", avg)));
+        }
+        key_lines.push(Line::from(legend).centered());
+        let title = match by_latency {
+            true => format!(" Key Latency ({}) ", scope),
+            false => format!(" Key Errors ({}) ", scope),
+        };
+        let keyboard_block = Block::default()
+            .title(Line::from(Span::styled(
+                title,
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )))
+            .title_bottom(
+                Line::from(" 'k' to switch view ")
+                    .style(Style::default().fg(Color::DarkGray))
+                    .centered(),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+        Paragraph::new(Text::from(key_lines))
+            .block(keyboard_block)
+            .render(area, buf);
     }
     /// Renders user options screen
     fn render_options(&self, area: Rect, buf: &mut Buffer) {
@@ -1428,9 +2998,9 @@ impl App {
         let outer_layout = Layout::default()
             .direction(Direction::Vertical)
             .constraints(vec![
-                Constraint::Percentage(25),
-                Constraint::Percentage(50),
-                Constraint::Percentage(25),
+                Constraint::Percentage(10),
+                Constraint::Percentage(80),
+                Constraint::Percentage(10),
             ])
             .split(area);
         let inner_layout = Layout::default()
@@ -1442,7 +3012,7 @@ impl App {
             ])
             .split(outer_layout[1]);
         let title = Line::from(vec![Span::styled(
-            format!(" User Config "),
+            " User Config ".to_string(),
             Style::default()
                 .fg(Color::Blue)
                 .add_modifier(Modifier::BOLD),
@@ -1451,28 +3021,46 @@ impl App {
             .title(title.centered())
             .borders(Borders::ALL)
             .border_set(border::THICK);
-        let round_time_options = vec!["30 Seconds", "1 Minute", "2 Minute"];
-        let text_theme_options = vec!["Default", "Lorem Ipsum", "Technology", "Food"];
-        let options_text = Text::from(vec![
-            Line::from(vec![Span::raw("")]),
-            Line::from(vec![Span::styled(
-                format!("Round Time"),
+        // Pack details shown under the theme choice
+        let pack_details = match self
+            .config
+            .text_theme_index
+            .checked_sub(BUILTIN_THEME_COUNT)
+            .and_then(|i| self.word_packs.get(i))
+        {
+            Some(pack) if pack.description.is_empty() => pack.language.clone(),
+            Some(pack) => format!("{} - {}", pack.language, pack.description),
+            None => String::new(),
+        };
+        let mut options_text = Text::from(vec![Line::from(vec![Span::raw("")])]);
+        for (i, row) in self.option_rows().into_iter().enumerate() {
+            let selected = self.config.choice_index == i;
+            if row == OptionRow::Save {
+                options_text.push_line(Line::from(vec![Span::styled(
+                    "Save".to_string(),
+                    Style::default()
+                        .fg(if selected { Color::Black } else { Color::White })
+                        .bg(if selected { Color::Green } else { Color::Reset })
+                        .add_modifier(Modifier::BOLD),
+                )]));
+                continue;
+            }
+            options_text.push_line(Line::from(vec![Span::styled(
+                row.label().to_string(),
                 Style::default()
                     .fg(Color::Yellow)
                     .add_modifier(Modifier::UNDERLINED),
-            )]),
-            Line::from(vec![Span::raw("")]),
-            Line::from(vec![
+            )]));
+            options_text.push_line(Line::from(vec![
                 Span::raw("< "),
                 Span::styled(
-                    format!("{}", round_time_options[self.config.round_time_index]),
+                    self.option_values(row)
+                        .get(self.option_index(row))
+                        .cloned()
+                        .unwrap_or_default(),
                     Style::default()
-                        .fg(if self.config.choice_index == 0 {
-                            Color::Black
-                        } else {
-                            Color::White
-                        })
-                        .bg(if self.config.choice_index == 0 {
+                        .fg(if selected { Color::Black } else { Color::White })
+                        .bg(if selected {
                             Color::LightBlue
                         } else {
                             Color::Reset
@@ -1480,107 +3068,1010 @@ impl App {
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw(" >"),
-            ]),
-            Line::from(vec![Span::raw("")]),
-            Line::from(vec![Span::styled(
-                format!("Word Theme"),
+            ]));
+            let details = match row {
+                OptionRow::TextTheme => pack_details.clone(),
+                OptionRow::Seed => "type digits, backspace to clear".to_string(),
+                OptionRow::WordCount => "type digits for a custom count".to_string(),
+                OptionRow::ErrorMode => match self.config.error_mode_index {
+                    1 => "the round ends on the first mistake".to_string(),
+                    2 => "wrong keys are refused until each word is exact".to_string(),
+                    _ => String::new(),
+                },
+                OptionRow::Pace => match self.config.pace_index {
+                    1 => "type digits to set the pace".to_string(),
+                    2 | 3 => "from round history for these settings".to_string(),
+                    _ => String::new(),
+                },
+                OptionRow::Ghost if self.config.ghost_index == 1 => {
+                    "race your best run over the same words".to_string()
+                }
+                OptionRow::AutoPause if self.config.auto_pause_index == 1 => {
+                    "pause when the terminal loses focus".to_string()
+                }
+                OptionRow::RoundTime => "type digits for a custom time in seconds".to_string(),
+                OptionRow::Corpus => "local .txt files from the corpus folder".to_string(),
+                OptionRow::Book if self.books.is_empty() => {
+                    "import with --import-book <file>".to_string()
+                }
+                _ => String::new(),
+            };
+            options_text.push_line(Line::from(vec![Span::styled(
+                details,
+                Style::default().fg(Color::DarkGray),
+            )]));
+        }
+        // Report packs that failed validation
+        if !self.pack_errors.is_empty() {
+            options_text.push_line(Line::from(""));
+            options_text.push_line(Line::from(Span::styled(
+                "Skipped word packs",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )));
+            for error in &self.pack_errors {
+                options_text.push_line(Line::from(Span::styled(
+                    error.clone(),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+        }
+        let options_paragraph = Paragraph::new(options_text)
+            .block(options_block)
+            .wrap(ratatui::widgets::Wrap { trim: true })
+            .alignment(Alignment::Center);
+        options_paragraph.render(inner_layout[1], buf);
+    }
+    /// Renders book progress with per chapter stats for the selected book
+    fn render_books(&self, area: Rect, buf: &mut Buffer) {
+        let padding_height = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Percentage(10),
+                Constraint::Percentage(80),
+                Constraint::Percentage(10),
+            ])
+            .split(area);
+        let padding_width = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![
+                Constraint::Percentage(15),
+                Constraint::Percentage(70),
+                Constraint::Percentage(15),
+            ])
+            .split(padding_height[1]);
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Percentage(35), Constraint::Percentage(65)])
+            .split(padding_width[1]);
+        let title_style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+        // Top block lists every imported book
+        let books_block = Block::default()
+            .title(Line::from(Span::styled(" Books ", title_style)))
+            .title_bottom(
+                Line::from(" j/k select - enter to type - b back ")
+                    .style(Style::default().fg(Color::DarkGray))
+                    .centered(),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+        let mut book_lines = vec![Line::from("")];
+        if self.books.is_empty() {
+            book_lines.push(Line::from("No books imported").centered());
+            book_lines.push(
+                Line::from(Span::styled(
+                    "TerminalType --import-book <file>",
+                    Style::default().fg(Color::Yellow),
+                ))
+                .centered(),
+            );
+        }
+        for (i, book) in self.books.iter().enumerate() {
+            let selected = i == self.book_choice;
+            book_lines.push(
+                Line::from(vec![
+                    Span::styled(
+                        book.title.clone(),
+                        Style::default()
+                            .fg(if selected { Color::Black } else { Color::White })
+                            .bg(if selected {
+                                Color::LightBlue
+                            } else {
+                                Color::Reset
+                            })
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("  {:.1} %", book.percent_complete()),
+                        Style::default().fg(Color::Green),
+                    ),
+                    Span::styled(
+                        format!("  {} words typed", book.total_typed()),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                ])
+                .centered(),
+            );
+        }
+        Paragraph::new(Text::from(book_lines))
+            .block(books_block)
+            .render(layout[0], buf);
+        // Bottom block shows chapters of the selected book around the saved position
+        let Some(book) = self.books.get(self.book_choice) else {
+            return;
+        };
+        let chapters_block = Block::default()
+            .title(Line::from(Span::styled(
+                format!(" Chapters ({}) ", book.title),
+                title_style,
+            )))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+        let current = book.chapter_at(book.position);
+        let visible = layout[1].height.saturating_sub(4) as usize;
+        let first = current.saturating_sub(visible / 2);
+        let mut chapter_lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!(
+                    "{:<32} {:>8} {:>8} {:>8}",
+                    "CHAPTER", "DONE", "TYPED", "WPM"
+                ),
                 Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::UNDERLINED),
-            )]),
-            Line::from(vec![Span::raw("")]),
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .centered(),
+        ];
+        for (i, chapter) in book.chapters.iter().enumerate().skip(first).take(visible) {
+            let end = book.chapter_end(i);
+            let length = end.saturating_sub(chapter.start).max(1);
+            let done = book.position.clamp(chapter.start, end) - chapter.start;
+            let stats = &book.stats[i];
+            let title: String = chapter.title.chars().take(32).collect();
+            chapter_lines.push(
+                Line::from(Span::styled(
+                    format!(
+                        "{:<32} {:>7.0}% {:>8} {:>8.0}",
+                        title,
+                        done as f64 / length as f64 * 100_f64,
+                        stats.typed,
+                        stats.wpm()
+                    ),
+                    Style::default().fg(if i == current {
+                        Color::Green
+                    } else {
+                        Color::Yellow
+                    }),
+                ))
+                .centered(),
+            );
+        }
+        Paragraph::new(Text::from(chapter_lines))
+            .block(chapters_block)
+            .render(layout[1], buf);
+    }
+    /// Render WPM over the round and raw WPM for each second, marking seconds with mistakes
+    fn render_wpm_chart(&self, area: Rect, buf: &mut Buffer) {
+        let mut wpm = vec![];
+        let mut raw = vec![];
+        let mut errors = vec![];
+        let mut worst: Option<(usize, f64)> = None;
+        let mut previous = Sample::default();
+        for sample in &self.samples {
+            let line = sample
+                .chars
+                .wpm(Duration::from_secs_f64(sample.seconds))
+                .net;
+            let typed = sample.chars.typed + sample.chars.spaces;
+            let typed_before = previous.chars.typed + previous.chars.spaces;
+            let interval = Duration::from_secs_f64(sample.seconds - previous.seconds);
+            wpm.push((sample.seconds, line));
+            raw.push((
+                sample.seconds,
+                stats::chars_wpm(typed.saturating_sub(typed_before), interval),
+            ));
+            // Mistakes are marked on the WPM line at the second they were made
+            let mistakes = sample.mistakes - previous.mistakes;
+            if mistakes > 0 {
+                errors.push((sample.seconds, line));
+                if worst.is_none_or(|(most, _)| mistakes > most) {
+                    worst = Some((mistakes, sample.seconds));
+                }
+            }
+            previous = *sample;
+        }
+        let top = wpm
+            .iter()
+            .chain(&raw)
+            .map(|(_, y)| *y)
+            .fold(10.0, f64::max)
+            .ceil();
+        let end = self.samples.last().map_or(1.0, |s| s.seconds);
+        let datasets = vec![
+            Dataset::default()
+                .name("raw")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::DarkGray))
+                .data(&raw),
+            Dataset::default()
+                .name("wpm")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Yellow))
+                .data(&wpm),
+            Dataset::default()
+                .name("errors")
+                .marker(Marker::Dot)
+                .graph_type(GraphType::Scatter)
+                .style(Style::default().fg(Color::Red))
+                .data(&errors),
+        ];
+        let worst_label = match worst {
+            Some((mistakes, seconds)) => format!(
+                " most errors: {} at {} ",
+                mistakes,
+                duration_label(seconds.ceil() as u64)
+            ),
+            None => " no errors ".to_string(),
+        };
+        let chart_block = Block::default()
+            .title(Line::from(Span::styled(
+                " Speed ",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )))
+            .title_bottom(
+                Line::from(worst_label)
+                    .style(Style::default().fg(Color::DarkGray))
+                    .centered(),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+        let label_style = Style::default().fg(Color::DarkGray);
+        Chart::new(datasets)
+            .block(chart_block)
+            .x_axis(
+                Axis::default()
+                    .style(label_style)
+                    .bounds([0.0, end])
+                    .labels(["0s".to_string(), duration_label(end.ceil() as u64)]),
+            )
+            .y_axis(
+                Axis::default()
+                    .style(label_style)
+                    .bounds([0.0, top])
+                    .labels([
+                        "0".to_string(),
+                        format!("{:.0}", top / 2.0),
+                        format!("{:.0}", top),
+                    ]),
+            )
+            .legend_position(Some(LegendPosition::TopLeft))
+            .hidden_legend_constraints((Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)))
+            .render(area, buf);
+    }
+    /// Render per minute WPM, accuracy and errors with a bar for each minute's speed
+    fn render_splits(&self, area: Rect, buf: &mut Buffer) {
+        let wpms: Vec<f64> = self
+            .splits
+            .iter()
+            .enumerate()
+            .map(|(minute, split)| {
+                // The last minute may be cut short
+                let seconds =
+                    (self.round_elapsed.as_secs_f64() - minute as f64 * 60.0).clamp(1.0, 60.0);
+                split.wpm(Duration::from_secs_f64(seconds)).net
+            })
+            .collect();
+        let fastest = wpms.iter().cloned().fold(1.0, f64::max);
+        // Change from the first minute to the last shows any fatigue
+        let trend = match (wpms.first(), wpms.last()) {
+            (Some(first), Some(last)) if *first > 0.0 => {
+                format!(
+                    " first to last minute: {:+.0}% ",
+                    (last / first - 1.0) * 100.0
+                )
+            }
+            _ => String::new(),
+        };
+        let splits_block = Block::default()
+            .title(Line::from(Span::styled(
+                " Splits (per minute) ",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )))
+            .title_bottom(
+                Line::from(trend)
+                    .style(Style::default().fg(Color::DarkGray))
+                    .centered(),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+        let mut split_lines = vec![
+            Line::from(Span::styled(
+                format!(
+                    "{:>3} {:>5} {:>7} {:>5}  {:<width$}",
+                    "MIN",
+                    "WPM",
+                    "ACC",
+                    "ERR",
+                    "",
+                    width = SPLIT_BAR_WIDTH
+                ),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .centered(),
+        ];
+        for (minute, (split, wpm)) in self.splits.iter().zip(&wpms).enumerate() {
+            let bar = (wpm / fastest * SPLIT_BAR_WIDTH as f64).round() as usize;
+            split_lines.push(
+                Line::from(vec![
+                    Span::styled(
+                        format!(
+                            "{:>3} {:>5.0} {:>6.1}% {:>5}  ",
+                            minute + 1,
+                            wpm,
+                            split.accuracy(),
+                            split.errors()
+                        ),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                    Span::styled(
+                        format!("{:<width$}", "█".repeat(bar), width = SPLIT_BAR_WIDTH),
+                        Style::default().fg(Color::Green),
+                    ),
+                ])
+                .centered(),
+            );
+        }
+        Paragraph::new(Text::from(split_lines))
+            .block(splits_block)
+            .render(area, buf);
+    }
+    /// Render today's daily status, the streak and past official results
+    fn render_daily(&self, area: Rect, buf: &mut Buffer) {
+        let padding_height = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Percentage(10),
+                Constraint::Percentage(80),
+                Constraint::Percentage(10),
+            ])
+            .split(area);
+        let padding_width = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![
+                Constraint::Percentage(25),
+                Constraint::Percentage(50),
+                Constraint::Percentage(25),
+            ])
+            .split(padding_height[1]);
+        let today = daily::today();
+        let daily_block = Block::default()
+            .title(Line::from(Span::styled(
+                format!(" Daily Challenge ({}) ", today.format("%Y-%m-%d")),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )))
+            .title_bottom(
+                Line::from(" enter to play - d back ")
+                    .style(Style::default().fg(Color::DarkGray))
+                    .centered(),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+        let label = Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD);
+        let value = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let today_result = match self.daily_results.iter().find(|r| r.date == today) {
+            Some(result) => format!("{} WPM ({:.1} %)", result.wpm, result.accuracy),
+            None => "not played yet".to_string(),
+        };
+        let best = self.daily_results.iter().map(|r| r.wpm).max();
+        let streak = match daily::streak(&self.daily_results, today) {
+            1 => "1 day".to_string(),
+            days => format!("{} days", days),
+        };
+        let mut daily_lines = vec![
+            Line::from(""),
             Line::from(vec![
-                Span::raw("< "),
+                Span::styled("TODAY: ", label),
+                Span::styled(today_result, value),
+            ])
+            .centered(),
+            Line::from(vec![
+                Span::styled("STREAK: ", label),
                 Span::styled(
-                    format!("{}", text_theme_options[self.config.text_theme_index]),
+                    streak,
                     Style::default()
-                        .fg(if self.config.choice_index == 1 {
-                            Color::Black
-                        } else {
-                            Color::White
-                        })
-                        .bg(if self.config.choice_index == 1 {
-                            Color::LightBlue
-                        } else {
-                            Color::Reset
-                        })
+                        .fg(Color::Green)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::raw(" >"),
-            ]),
-            Line::from(vec![Span::raw("")]),
-            Line::from(vec![Span::styled(
-                format!("Save"),
+            ])
+            .centered(),
+            Line::from(vec![
+                Span::styled("BEST: ", label),
+                Span::styled(
+                    best.map_or("-".to_string(), |wpm| format!("{} WPM", wpm)),
+                    value,
+                ),
+            ])
+            .centered(),
+            Line::from(Span::styled(
+                "1 minute of English 1k words, only the first attempt each day counts",
+                Style::default().fg(Color::DarkGray),
+            ))
+            .centered(),
+            Line::from(""),
+            Line::from(Span::styled(
+                format!("{:<12} {:>5} {:>7}", "DATE", "WPM", "ACC"),
+                label,
+            ))
+            .centered(),
+        ];
+        let visible = padding_width[1].height.saturating_sub(10) as usize;
+        for result in self.daily_results.iter().rev().take(visible) {
+            daily_lines.push(
+                Line::from(Span::styled(
+                    format!(
+                        "{:<12} {:>5} {:>6.1}%",
+                        result.date.format("%Y-%m-%d"),
+                        result.wpm,
+                        result.accuracy
+                    ),
+                    Style::default().fg(Color::Yellow),
+                ))
+                .centered(),
+            );
+        }
+        Paragraph::new(Text::from(daily_lines))
+            .block(daily_block)
+            .render(padding_width[1], buf);
+    }
+    /// Render the most recent rounds, newest first
+    fn render_history(&self, area: Rect, buf: &mut Buffer) {
+        let padding_height = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Percentage(10),
+                Constraint::Percentage(80),
+                Constraint::Percentage(10),
+            ])
+            .split(area);
+        let padding_width = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![
+                Constraint::Percentage(15),
+                Constraint::Percentage(70),
+                Constraint::Percentage(15),
+            ])
+            .split(padding_height[1]);
+        let history_block = Block::default()
+            .title(Line::from(Span::styled(
+                format!(" History ({} rounds) ", self.history.len()),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )))
+            .title_bottom(
+                Line::from(" h back ")
+                    .style(Style::default().fg(Color::DarkGray))
+                    .centered(),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+        let mut history_lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!(
+                    "{:<12} {:<6} {:>5} {:>7} {:>8}  {:<24}",
+                    "DATE", "TIME", "WPM", "ACC", "LENGTH", "MODE"
+                ),
                 Style::default()
-                    .fg(if self.config.choice_index == 2 {
-                        Color::Black
-                    } else {
-                        Color::White
-                    })
-                    .bg(if self.config.choice_index == 2 {
-                        Color::Green
-                    } else {
-                        Color::Reset
-                    })
+                    .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
-            )]),
-        ]);
-        let options_paragraph = Paragraph::new(options_text)
-            .block(options_block)
-            .alignment(Alignment::Center);
-        options_paragraph.render(inner_layout[1], buf);
+            ))
+            .centered(),
+        ];
+        if self.history.is_empty() {
+            history_lines.push(Line::from("No rounds recorded").centered());
+        }
+        let visible = padding_width[1].height.saturating_sub(4) as usize;
+        for entry in self.history.iter().rev().take(visible) {
+            history_lines.push(
+                Line::from(Span::styled(
+                    format!(
+                        "{:<12} {:<6} {:>5} {:>6.1}% {:>8}  {:<24}",
+                        entry.date,
+                        entry.time,
+                        entry.wpm,
+                        entry.accuracy,
+                        duration_label(entry.seconds),
+                        match entry.paused {
+                            true => format!("{} (paused)", entry.category),
+                            false => entry.category.clone(),
+                        }
+                    ),
+                    Style::default().fg(if entry.category.ends_with("zen") {
+                        Color::Cyan
+                    } else {
+                        Color::Yellow
+                    }),
+                ))
+                .centered(),
+            );
+        }
+        Paragraph::new(Text::from(history_lines))
+            .block(history_block)
+            .render(padding_width[1], buf);
+    }
+    /// Option rows available for the current config
+    fn option_rows(&self) -> Vec<OptionRow> {
+        let mut rows = vec![OptionRow::RoundMode];
+        match self.config.round_mode_index {
+            1 => rows.push(OptionRow::WordCount),
+            2 | 3 => {}
+            4 => rows.push(OptionRow::Endurance),
+            _ => rows.push(OptionRow::RoundTime),
+        }
+        rows.push(OptionRow::ErrorMode);
+        rows.push(OptionRow::Pace);
+        rows.push(OptionRow::Ghost);
+        rows.push(OptionRow::AutoPause);
+        rows.push(OptionRow::TextSource);
+        match TextSource::from_index(self.config.text_source_index) {
+            TextSource::Words => rows.extend([
+                OptionRow::TextTheme,
+                OptionRow::Punctuation,
+                OptionRow::Numbers,
+                OptionRow::Capitalization,
+            ]),
+            TextSource::Quotes => rows.push(OptionRow::QuoteLength),
+            TextSource::Code => {
+                rows.push(OptionRow::CodeLanguage);
+                rows.push(OptionRow::SkipIndent);
+            }
+            TextSource::Book => rows.push(OptionRow::Book),
+            TextSource::Prose => rows.push(OptionRow::Corpus),
+            TextSource::File => {}
+        }
+        rows.push(OptionRow::Seed);
+        rows.push(OptionRow::Save);
+        rows
+    }
+    /// Display values for an option row
+    fn option_values(&self, row: OptionRow) -> Vec<String> {
+        let values: &[&str] = match row {
+            OptionRow::RoundMode => &["Timed", "Word Count", "Zen", "Survival", "Endurance"],
+            OptionRow::Endurance => &["5 Minutes", "10 Minutes", "15 Minutes"],
+            OptionRow::ErrorMode => &["Normal", "Sudden Death", "Strict"],
+            OptionRow::Ghost => &["Off", "On"],
+            OptionRow::AutoPause => &["Off", "On"],
+            OptionRow::Pace => {
+                return vec![
+                    "Off".to_string(),
+                    format!(
+                        "Fixed: {} WPM",
+                        self.config.pace_wpm.unwrap_or(DEFAULT_PACE_WPM)
+                    ),
+                    "Personal Best".to_string(),
+                    "Last Round".to_string(),
+                ];
+            }
+            OptionRow::RoundTime => {
+                return vec![
+                    "30 Seconds".to_string(),
+                    "1 Minute".to_string(),
+                    "2 Minute".to_string(),
+                    match self.config.custom_seconds {
+                        Some(seconds) => format!("Custom: {}", duration_label(seconds)),
+                        None => "Custom".to_string(),
+                    },
+                ];
+            }
+            OptionRow::WordCount => {
+                let mut values: Vec<String> = WORD_COUNTS.iter().map(|c| c.to_string()).collect();
+                values.push(match self.config.custom_words {
+                    Some(words) => format!("Custom: {}", words),
+                    None => "Custom".to_string(),
+                });
+                return values;
+            }
+            OptionRow::TextSource => &["Words", "Quotes", "Code", "Book", "Prose"],
+            OptionRow::Corpus => &["Bundled", "Local Files"],
+            OptionRow::TextTheme => &[
+                "Default",
+                "Lorem Ipsum",
+                "Technology",
+                "Food",
+                "English 200",
+                "English 1k",
+                "English 10k",
+            ],
+            OptionRow::QuoteLength => &["Any", "Short", "Medium", "Long"],
+            OptionRow::CodeLanguage => &["Any", "Rust", "Python", "Go", "Local Files"],
+            OptionRow::SkipIndent => &["Off", "On"],
+            OptionRow::Punctuation => &["Off", "On"],
+            OptionRow::Numbers => &["Off", "On"],
+            OptionRow::Capitalization => &["Off", "Sentence Case", "Random Caps"],
+            OptionRow::Book if self.books.is_empty() => &["No books imported"],
+            OptionRow::Book => return self.books.iter().map(|b| b.title.clone()).collect(),
+            OptionRow::Seed => {
+                return vec![match self.config.seed {
+                    Some(seed) => seed.to_string(),
+                    None => "Random".to_string(),
+                }];
+            }
+            OptionRow::Save => &[],
+        };
+        let mut values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+        if row == OptionRow::TextTheme {
+            values.extend(self.word_packs.iter().map(|p| p.name.clone()));
+        }
+        values
+    }
+    /// Current value index of an option row
+    fn option_index(&self, row: OptionRow) -> usize {
+        match row {
+            OptionRow::RoundMode => self.config.round_mode_index,
+            OptionRow::RoundTime => self.config.round_time_index,
+            OptionRow::WordCount => self.config.word_count_index,
+            OptionRow::Endurance => self.config.endurance_index,
+            OptionRow::ErrorMode => self.config.error_mode_index,
+            OptionRow::Pace => self.config.pace_index,
+            OptionRow::Ghost => self.config.ghost_index,
+            OptionRow::AutoPause => self.config.auto_pause_index,
+            OptionRow::TextSource => self.config.text_source_index,
+            OptionRow::TextTheme => self.config.text_theme_index,
+            OptionRow::QuoteLength => self.config.quote_length_index,
+            OptionRow::CodeLanguage => self.config.code_language_index,
+            OptionRow::SkipIndent => self.config.skip_indent_index,
+            OptionRow::Punctuation => self.config.punctuation_index,
+            OptionRow::Numbers => self.config.numbers_index,
+            OptionRow::Capitalization => self.config.capitalization_index,
+            OptionRow::Book => self.config.book_index,
+            OptionRow::Corpus => self.config.corpus_index,
+            OptionRow::Seed | OptionRow::Save => 0,
+        }
+    }
+    /// Config index backing an option row
+    fn option_index_mut(&mut self, row: OptionRow) -> Option<&mut usize> {
+        match row {
+            OptionRow::RoundMode => Some(&mut self.config.round_mode_index),
+            OptionRow::RoundTime => Some(&mut self.config.round_time_index),
+            OptionRow::WordCount => Some(&mut self.config.word_count_index),
+            OptionRow::Endurance => Some(&mut self.config.endurance_index),
+            OptionRow::ErrorMode => Some(&mut self.config.error_mode_index),
+            OptionRow::Pace => Some(&mut self.config.pace_index),
+            OptionRow::Ghost => Some(&mut self.config.ghost_index),
+            OptionRow::AutoPause => Some(&mut self.config.auto_pause_index),
+            OptionRow::TextSource => Some(&mut self.config.text_source_index),
+            OptionRow::TextTheme => Some(&mut self.config.text_theme_index),
+            OptionRow::QuoteLength => Some(&mut self.config.quote_length_index),
+            OptionRow::CodeLanguage => Some(&mut self.config.code_language_index),
+            OptionRow::SkipIndent => Some(&mut self.config.skip_indent_index),
+            OptionRow::Punctuation => Some(&mut self.config.punctuation_index),
+            OptionRow::Numbers => Some(&mut self.config.numbers_index),
+            OptionRow::Capitalization => Some(&mut self.config.capitalization_index),
+            OptionRow::Book => Some(&mut self.config.book_index),
+            OptionRow::Corpus => Some(&mut self.config.corpus_index),
+            OptionRow::Seed | OptionRow::Save => None,
+        }
+    }
+    /// Steps the selected option row forwards or backwards
+    fn cycle_option(&mut self, forward: bool) {
+        let row = self.option_rows()[self.config.choice_index];
+        let count = self.option_values(row).len();
+        if let Some(index) = self.option_index_mut(row) {
+            *index = if forward {
+                (*index + 1) % count
+            } else {
+                (*index + count - 1) % count
+            };
+        }
+        if row == OptionRow::TextTheme {
+            self.config.text_pack = self
+                .config
+                .text_theme_index
+                .checked_sub(BUILTIN_THEME_COUNT)
+                .map(|i| self.word_packs[i].name.clone());
+        }
+        if TextSource::from_index(self.config.text_source_index) == TextSource::Book {
+            self.load_books();
+        }
+        self.clamp_choice();
+    }
+    /// Reads the imported books the first time they are needed
+    fn load_books(&mut self) {
+        if !self.books_loaded {
+            self.books = books::load_books();
+            self.books_loaded = true;
+        }
+    }
+    /// Keeps the selected option on screen after the option rows change
+    fn clamp_choice(&mut self) {
+        let last = self.option_rows().len() - 1;
+        self.config.choice_index = self.config.choice_index.min(last);
+    }
+    /// Types or deletes a digit of the seed, pace, custom time or custom word count
+    fn edit_option_number(&mut self, row: OptionRow, digit: Option<char>) {
+        let (value, limit) = match row {
+            OptionRow::Seed => (&mut self.config.seed, u64::MAX),
+            // Typing a number selects the custom option
+            OptionRow::RoundTime => {
+                self.config.round_time_index = ROUND_TIMES.len();
+                (&mut self.config.custom_seconds, MAX_ROUND_SECONDS)
+            }
+            OptionRow::WordCount => {
+                self.config.word_count_index = WORD_COUNTS.len();
+                (&mut self.config.custom_words, MAX_WORD_GOAL)
+            }
+            OptionRow::Pace => {
+                self.config.pace_index = 1;
+                (&mut self.config.pace_wpm, MAX_PACE_WPM)
+            }
+            _ => return,
+        };
+        let mut digits = value.map(|v| v.to_string()).unwrap_or_default();
+        match digit {
+            Some(c) => digits.push(c),
+            None => {
+                digits.pop();
+            }
+        }
+        // An empty seed means random, an empty time or count falls back to the default
+        match digits.parse::<u64>() {
+            Ok(number) if number <= limit => *value = Some(number),
+            Ok(_) => {}
+            Err(_) if digit.is_none() => *value = None,
+            Err(_) => {}
+        }
+    }
+    /// Leaderboard key so scores are only compared against the same text and modifiers
+    fn leaderboard_category(&self) -> String {
+        let mut parts = vec![match self.text_source {
+            TextSource::Words => "words",
+            TextSource::Quotes => "quotes",
+            TextSource::Code => "code",
+            TextSource::Book => "book",
+            TextSource::Prose => "prose",
+            TextSource::File => "file",
+        }];
+        if self.text_source == TextSource::Words {
+            parts.extend(self.modifiers.tags());
+        }
+        // Word count rounds are ranked apart from timed rounds
+        let goal_tag = self.word_goal.map(|goal| format!("{}w", goal));
+        parts.extend(goal_tag.as_deref());
+        // Timed rounds are ranked apart by their length, endurance sessions in minutes
+        let time_tag = match self.endurance {
+            true => Some(format!("endurance{}m", self.round_time / 60)),
+            false => self.is_timed().then(|| format!("{}s", self.round_time)),
+        };
+        parts.extend(time_tag.as_deref());
+        if self.zen {
+            parts.push("zen");
+        }
+        if self.survival.is_some() {
+            parts.push("survival");
+        }
+        if self.daily.is_some() {
+            parts.push("daily");
+        }
+        match self.error_mode {
+            ErrorMode::Normal => {}
+            ErrorMode::SuddenDeath => parts.push("sd"),
+            ErrorMode::Strict => parts.push("strict"),
+        }
+        parts.join("+")
+    }
+    /// Ghost key, adds everything the leaderboard leaves out that changes the words
+    fn ghost_category(&self) -> String {
+        let mut parts = vec![self.leaderboard_category()];
+        match self.text_source {
+            TextSource::Words => parts.push(self.text_theme.key()),
+            TextSource::Quotes => parts.push(
+                format!(
+                    "{:?}",
+                    QuoteLength::from_index(self.config.quote_length_index)
+                )
+                .to_lowercase(),
+            ),
+            TextSource::Code => {
+                parts.push(format!("{:?}", self.code_language).to_lowercase());
+                if self.skip_indent {
+                    parts.push("noindent".to_string());
+                }
+            }
+            TextSource::Prose => parts
+                .push(format!("{:?}", Corpus::from_index(self.config.corpus_index)).to_lowercase()),
+            TextSource::Book | TextSource::File => {}
+        }
+        parts.join("+")
+    }
+    /// Pace caret speed from the config, personal best and last round come from history
+    fn resolve_pace(&self) -> Option<u64> {
+        let category = self.leaderboard_category();
+        let history = || {
+            history::load_history()
+                .into_iter()
+                .filter(|entry| entry.category == category)
+                .map(|entry| entry.wpm as u64)
+        };
+        let wpm = match self.config.pace_index {
+            1 => Some(self.config.pace_wpm.unwrap_or(DEFAULT_PACE_WPM)),
+            2 => history().max(),
+            3 => history().next_back(),
+            _ => None,
+        };
+        wpm.filter(|wpm| *wpm > 0)
+    }
+    /// Characters including spaces before a word, the pace caret is measured in these
+    fn chars_before(&self, word: usize) -> usize {
+        self.target_words
+            .iter()
+            .take(word)
+            .map(|w| grapheme_count(w) + 1)
+            .sum()
+    }
+    /// Characters the pace caret has covered after `elapsed`, 5 per word
+    fn pace_chars(&self, elapsed: Duration) -> Option<f64> {
+        self.pace_wpm
+            .map(|wpm| wpm as f64 * CHARS_PER_WORD / 60.0 * elapsed.as_secs_f64())
+    }
+    /// Word and character the pace caret is on, the word length means its trailing space
+    fn pace_position(&self, elapsed: Duration) -> Option<(usize, usize)> {
+        let mut remaining = self.pace_chars(elapsed)? as usize;
+        for (i, word) in self.target_words.iter().enumerate() {
+            let length = grapheme_count(word);
+            if remaining <= length {
+                return Some((i, remaining));
+            }
+            remaining -= length + 1;
+        }
+        None
+    }
+    /// Characters the user is ahead of the pace caret, negative when behind
+    fn pace_lead(&self, elapsed: Duration) -> Option<f64> {
+        let typed = self.chars_before(self.word_index) + self.char_index;
+        Some(typed as f64 - self.pace_chars(elapsed)?)
+    }
+    /// Word and character the ghost had reached after `elapsed`
+    fn ghost_position(&self, elapsed: Duration) -> Option<(usize, usize)> {
+        let ghost = self.ghost.as_ref()?;
+        Some(ghost.position_at(elapsed.as_millis() as u64))
+    }
+    /// Characters the user is ahead of the ghost, negative when behind
+    fn ghost_lead(&self, elapsed: Duration) -> Option<i64> {
+        let (word, char) = self.ghost_position(elapsed)?;
+        let typed = self.chars_before(self.word_index) + self.char_index;
+        Some(typed as i64 - (self.chars_before(word) + char) as i64)
+    }
+    /// Timed rounds end when the clock runs out
+    fn is_timed(&self) -> bool {
+        self.word_goal.is_none() && !self.zen && self.survival.is_none()
+    }
+    /// Survival and sudden death leaderboards count words instead of WPM
+    fn ranks_by_words(&self) -> bool {
+        self.survival.is_some() || self.error_mode == ErrorMode::SuddenDeath
+    }
+    /// Top scores recorded for the current leaderboard category
+    fn category_scores(&self) -> Vec<&TopScore> {
+        let category = self.leaderboard_category();
+        match &self.top_scores {
+            Some(scores) => scores.iter().filter(|s| s.category == category).collect(),
+            None => vec![],
+        }
+    }
+    /// Quote and file rounds finish once every word is typed
+    fn text_complete(&self) -> bool {
+        self.text_fully_loaded() && self.word_index >= self.target_words.len()
     }
-    // Reurns round time for countdown
-    fn get_round_time(&self) -> u64 {
-        match self.round_time {
-            RoundTime::Default => return 30,
-            RoundTime::Min => return 60,
-            RoundTime::TwoMin => return 120,
+    /// Books and files are typed in order so a seed would not reproduce them
+    fn uses_seed(&self) -> bool {
+        !matches!(self.text_source, TextSource::Book | TextSource::File)
+    }
+    /// Saves how far through the book this round got and its per chapter totals
+    fn record_book_progress(&mut self) {
+        let Some(i) = self.active_book else {
+            return;
+        };
+        let words: Vec<CharStats> = (0..self.word_index)
+            .map(|word| {
+                let (typed, target) = self.word_graphemes(word);
+                CharStats::word(&typed, &target, !self.timed_out(word))
+            })
+            .collect();
+        let book = &mut self.books[i];
+        book.record_round(self.book_start, &words, self.round_elapsed.as_secs_f64());
+        book.save_progress();
+    }
+    /// True when the text has a fixed end and all of it is on screen
+    fn text_fully_loaded(&self) -> bool {
+        if self.word_goal.is_some() {
+            return true;
+        }
+        match (&self.text_source, &self.text_file) {
+            (TextSource::Quotes, _) => true,
+            (TextSource::Book, _) => self.active_book.is_some_and(|i| {
+                self.book_start + self.target_words.len() >= self.books[i].words.len()
+            }),
+            (TextSource::File, Some(file)) => self.target_words.len() >= file.words.len(),
+            _ => false,
+        }
+    }
+    /// Typed and target characters of a word, compared per grapheme so accented and wide characters count once
+    fn word_graphemes(&self, word: usize) -> (Vec<&str>, Vec<&str>) {
+        let mut typed_chars = self.typed_words.get(word).map_or(vec![], |w| graphemes(w));
+        let mut target_chars = self.target_words.get(word).map_or(vec![], |w| graphemes(w));
+        // Auto skipped indentation was never typed by the user
+        if self.skip_indent {
+            let indent = target_chars
+                .iter()
+                .take_while(|c| c.trim().is_empty())
+                .count();
+            if typed_chars.len() >= indent && typed_chars[..indent] == target_chars[..indent] {
+                typed_chars.drain(..indent);
+                target_chars.drain(..indent);
+            }
         }
+        (typed_chars, target_chars)
     }
-    // Returns end of round statistics
+    /// Character totals so far, the word being typed still counts towards speed
+    fn char_stats(&self) -> CharStats {
+        let mut chars = CharStats::default();
+        for word in 0..=self.word_index {
+            let (typed_chars, target_chars) = self.word_graphemes(word);
+            let finished = word < self.word_index && !self.timed_out(word);
+            chars += CharStats::word(&typed_chars, &target_chars, finished);
+        }
+        chars
+    }
+    // Returns end of round statistics, every WPM figure comes from the character totals here
     fn get_accuracy(&self) -> RoundResult {
         let num_words_typed = self.word_index;
-        let typed_words = &self.typed_words[..num_words_typed];
-        let comparison_words = &self.target_words[..num_words_typed];
-        let mut total_chars = 0;
-        let mut correct_chars = 0;
+        let chars = self.char_stats();
         let mut correct_words = 0;
+        let mut total_symbols = 0;
+        let mut correct_symbols = 0;
 
-        for (typed_word, target_word) in typed_words.iter().zip(comparison_words.iter()) {
-            let typed_chars: Vec<char> = typed_word.chars().collect();
-            let target_chars: Vec<char> = target_word.chars().collect();
-            total_chars += typed_chars.len();
-
-            if typed_word == target_word {
-                correct_words += 1;
-                correct_chars += typed_chars.len();
-            } else {
-                for (typed_c, target_c) in typed_chars.iter().zip(target_chars.iter()) {
-                    if typed_c == target_c {
-                        correct_chars += 1;
+        for word in 0..num_words_typed {
+            let (typed_chars, target_chars) = self.word_graphemes(word);
+            for (j, target_c) in target_chars.iter().enumerate() {
+                if target_c.chars().all(code::is_symbol) {
+                    total_symbols += 1;
+                    if typed_chars.get(j) == Some(target_c) {
+                        correct_symbols += 1;
                     }
                 }
             }
+            if self.typed_words[word] == self.target_words[word] {
+                correct_words += 1;
+            }
         }
-        let incorrect_chars = total_chars - correct_chars;
-        let percentage_words = (correct_words as f64 / num_words_typed as f64) * 100 as f64;
-        let percentage_chars = (correct_chars as f64 / total_chars as f64) * 100 as f64;
-        let res = RoundResult {
-            correct_words: correct_words,
-            total_chars: total_chars,
-            correct_chars: correct_chars,
-            incorrect_chars: incorrect_chars,
-            percentage_words: percentage_words,
-            percentage_chars: percentage_chars,
+        let percentage_words = match num_words_typed {
+            0 => 0.0,
+            _ => (correct_words as f64 / num_words_typed as f64) * 100_f64,
+        };
+        let percentage_symbols = match total_symbols {
+            0 => 0.0,
+            _ => (correct_symbols as f64 / total_symbols as f64) * 100_f64,
         };
-        return res;
+        RoundResult {
+            correct_words,
+            chars,
+            wpm: chars.wpm(self.round_elapsed),
+            percentage_words,
+            total_symbols,
+            correct_symbols,
+            percentage_symbols,
+        }
     }
     /// File input to get local leaderboard
     fn get_leaderboard_file_contents() -> Option<Vec<TopScore>> {
         // Get file path
-        let home_dir = dirs::home_dir()?;
-        let leaderboard_file_path = home_dir.join(".local/share/TerminalType/leaderboard.txt");
+        let leaderboard_file_path = data_dir()?.join("leaderboard.txt");
         let contents = fs::read_to_string(leaderboard_file_path).ok()?;
         if contents.trim().is_empty() {
             return None;
@@ -1588,13 +4079,27 @@ impl App {
         // Extract scores
         let mut top_scores = Vec::<TopScore>::new();
         for line in contents.lines() {
-            let mut parts = line.trim().split_whitespace();
+            let mut parts = line.split_whitespace();
             let date = parts.next()?;
             let wpm_str = parts.next()?;
             let wpm_value = wpm_str.parse::<usize>().ok()?;
+            // Scores saved before categories existed were plain word rounds
+            let category = parts.next().unwrap_or("words");
+            // Optional seed, then `paused` for rounds that were paused
+            let mut seed = None;
+            let mut paused = false;
+            for part in parts {
+                match part {
+                    "paused" => paused = true,
+                    part => seed = seed.or(part.parse::<u64>().ok()),
+                }
+            }
             top_scores.push(TopScore {
                 date: date.to_string(),
                 wpm_score: wpm_value,
+                category: category.to_string(),
+                seed,
+                paused,
             });
         }
         // Return result
@@ -1607,11 +4112,10 @@ impl App {
     /// File outpur for round results in top 10
     fn update_leaderboard_file_contents(&self, new_top_score: TopScore) {
         // Get file path
-        let home_dir = match dirs::home_dir() {
+        let leaderboard_dir = match data_dir() {
             Some(dir) => dir,
             None => return,
         };
-        let leaderboard_dir = home_dir.join(".local/share/TerminalType");
         let leaderboard_file_path = leaderboard_dir.join("leaderboard.txt");
         // Validate exists
         if let Err(e) = fs::create_dir_all(&leaderboard_dir) {
@@ -1619,16 +4123,30 @@ impl App {
             return;
         }
         // Load existing top_scores
-        let mut scores = self.top_scores.clone().unwrap_or_else(Vec::new);
+        let mut scores = self.top_scores.clone().unwrap_or_default();
         scores.push(new_top_score);
         // Sort by WPM
-        scores.sort_by(|a, b| b.wpm_score.cmp(&a.wpm_score));
-        // Keep top 10
-        scores.truncate(10);
+        scores.sort_by_key(|s| std::cmp::Reverse(s.wpm_score));
+        // Keep top 10 per category
+        let mut category_counts: HashMap<String, usize> = HashMap::new();
+        scores.retain(|s| {
+            let count = category_counts.entry(s.category.clone()).or_insert(0);
+            *count += 1;
+            *count <= 10
+        });
         // Format lines
         let lines: Vec<String> = scores
             .iter()
-            .map(|s| format!("{} {}", s.date, s.wpm_score))
+            .map(|s| {
+                let mut line = format!("{} {} {}", s.date, s.wpm_score, s.category);
+                if let Some(seed) = s.seed {
+                    line.push_str(&format!(" {}", seed));
+                }
+                if s.paused {
+                    line.push_str(" paused");
+                }
+                line
+            })
             .collect();
         // Write to file
         match File::create(&leaderboard_file_path) {
@@ -1648,17 +4166,33 @@ impl Widget for &App {
             CurrentScreen::Main => self.render_main(area, buf),
             CurrentScreen::EndRound => self.render_end_screen(area, buf),
             CurrentScreen::ShowOptions => self.render_options(area, buf),
+            CurrentScreen::Books => self.render_books(area, buf),
+            CurrentScreen::History => self.render_history(area, buf),
+            CurrentScreen::Daily => self.render_daily(area, buf),
         }
     }
 }
+/// Local data directory for leaderboard and user content
+pub fn data_dir() -> Option<PathBuf> {
+    Some(dirs::home_dir()?.join(".local/share/TerminalType"))
+}
 // Helper function to extract n random words from a list
-fn generate_words(words: &[&str], count: usize) -> Vec<String> {
-    let mut rng = rand::rng();
+// Weighted lists are in rank order and sampled with Zipf weights instead of uniformly
+fn generate_words<R: Rng>(
+    words: &[&str],
+    weighted: bool,
+    count: usize,
+    rng: &mut R,
+) -> Vec<String> {
+    let sampler = weighted.then(|| ZipfSampler::new(words.len()));
     let mut past_ten_words = VecDeque::new();
     let mut random_words = Vec::new();
     while random_words.len() < count {
-        let word = words.choose(&mut rng).unwrap().to_string();
-        if past_ten_words.len() < 10 {
+        let word = match &sampler {
+            Some(sampler) => words[sampler.sample(rng)].to_string(),
+            None => words.choose(rng).unwrap().to_string(),
+        };
+        if past_ten_words.len() < RECENT_WORD_WINDOW {
             if past_ten_words.contains(&word) {
                 continue;
             } else {
@@ -1675,12 +4209,54 @@ fn generate_words(words: &[&str], count: usize) -> Vec<String> {
             }
         }
     }
-    return random_words;
+    random_words
+}
+/// Short label for a round length, e.g. 45s, 2 min or 1m 30s
+fn duration_label(seconds: u64) -> String {
+    match (seconds / 60, seconds % 60) {
+        (0, s) => format!("{}s", s),
+        (m, 0) => format!("{} min", m),
+        (m, s) => format!("{}m {}s", m, s),
+    }
 }
 /// Main function to run app
 fn main() -> io::Result<()> {
+    let args = match CliArgs::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if args.help {
+        println!("{}", cli::USAGE);
+        return Ok(());
+    }
+    // Read the text before the terminal switches to the TUI so errors are visible
+    let text_file = match args.text.as_deref().map(TextFile::load).transpose() {
+        Ok(text_file) => text_file,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    if let Some(path) = &args.import_book {
+        match books::import_book(std::path::Path::new(path)) {
+            Ok(summary) => {
+                println!("{}", summary);
+                return Ok(());
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+    }
     let mut terminal = ratatui::init();
-    let app_result = App::new().run(&mut terminal);
+    // Focus events let rounds pause on focus loss, terminals without them just never auto pause
+    let _ = execute!(io::stdout(), EnableFocusChange);
+    let app_result = App::new(&args, text_file).run(&mut terminal);
+    let _ = execute!(io::stdout(), DisableFocusChange);
     ratatui::restore();
     app_result
 }
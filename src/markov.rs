@@ -0,0 +1,240 @@
+use rand::{Rng, seq::IndexedRandom};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{data_dir, quotes::QUOTES};
+
+/// Bundled prose the default model is trained on, along with the quote corpus
+const BUNDLED_CORPUS: &str = include_str!("data/corpus.txt");
+/// Files larger than this are skipped when reading the corpus directory
+const MAX_CORPUS_FILE_BYTES: u64 = 4 * 1024 * 1024;
+/// Sentences are cut off after this many words if no full stop turns up
+const MAX_SENTENCE_WORDS: usize = 30;
+/// Chance of following the last word alone when a pair only had one follower
+const BACKOFF_CHANCE: f64 = 0.25;
+
+/// Used for user corpus config
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Corpus {
+    #[default]
+    Bundled,
+    Local,
+}
+impl Corpus {
+    /// Maps config index to corpus
+    pub fn from_index(index: usize) -> Corpus {
+        match index {
+            1 => Corpus::Local,
+            _ => Corpus::Bundled,
+        }
+    }
+}
+/// Word level Markov chain that produces sentences in the style of its corpus
+///
+/// Pairs of words predict the next word, sometimes falling back to the last word
+/// alone when a pair only ever had one follower so output does not copy the corpus
+#[derive(Debug, Default)]
+pub struct MarkovModel {
+    starts: Vec<(String, String)>,
+    pairs: HashMap<(String, String), Vec<String>>,
+    singles: HashMap<String, Vec<String>>,
+}
+impl MarkovModel {
+    /// Trains on the chosen corpus, local files fall back to the bundled text
+    pub fn load(corpus: Corpus) -> MarkovModel {
+        if corpus == Corpus::Local
+            && let Some(text) = local_corpus()
+        {
+            let model = MarkovModel::train(&text);
+            if !model.starts.is_empty() {
+                return model;
+            }
+        }
+        let mut text = BUNDLED_CORPUS.to_string();
+        for quote in QUOTES {
+            text.push('\n');
+            text.push_str(quote.text);
+        }
+        MarkovModel::train(&text)
+    }
+    /// Builds transition tables, a sentence starts after any word ending in `.?!`
+    pub fn train(text: &str) -> MarkovModel {
+        let mut model = MarkovModel::default();
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let mut sentence_start = true;
+        for (i, word) in words.iter().enumerate() {
+            if sentence_start
+                && let Some(next) = words.get(i + 1)
+                && word.starts_with(|c: char| c.is_uppercase())
+            {
+                model.starts.push((word.to_string(), next.to_string()));
+            }
+            sentence_start = ends_sentence(word);
+            if let Some(next) = words.get(i + 1) {
+                model
+                    .singles
+                    .entry(word.to_string())
+                    .or_default()
+                    .push(next.to_string());
+            }
+            if let (Some(next), Some(after)) = (words.get(i + 1), words.get(i + 2)) {
+                model
+                    .pairs
+                    .entry((word.to_string(), next.to_string()))
+                    .or_default()
+                    .push(after.to_string());
+            }
+        }
+        model
+    }
+    /// Generates whole sentences until at least `count` words are produced
+    pub fn generate<R: Rng>(&self, count: usize, rng: &mut R) -> Vec<String> {
+        let mut words = Vec::new();
+        while words.len() < count {
+            let sentence = self.sentence(rng);
+            if sentence.is_empty() {
+                break;
+            }
+            words.extend(sentence);
+        }
+        words
+    }
+    /// Walks the chain from a random sentence start to the next full stop
+    fn sentence<R: Rng>(&self, rng: &mut R) -> Vec<String> {
+        let Some((first, second)) = self.starts.choose(rng) else {
+            return vec![];
+        };
+        let mut sentence = vec![first.clone(), second.clone()];
+        while !ends_sentence(&sentence[sentence.len() - 1]) {
+            if sentence.len() >= MAX_SENTENCE_WORDS {
+                sentence.last_mut().expect("sentence has words").push('.');
+                break;
+            }
+            let last = &sentence[sentence.len() - 1];
+            let pair = (sentence[sentence.len() - 2].clone(), last.clone());
+            let followers = match self.pairs.get(&pair) {
+                Some(options) if options.iter().any(|w| w != &options[0]) => Some(options),
+                Some(options) if !rng.random_bool(BACKOFF_CHANCE) => Some(options),
+                Some(options) => self.singles.get(last).or(Some(options)),
+                None => self.singles.get(last),
+            };
+            match followers.and_then(|f| f.choose(rng)) {
+                Some(next) => sentence.push(next.clone()),
+                None => {
+                    sentence.last_mut().expect("sentence has words").push('.');
+                    break;
+                }
+            }
+        }
+        sentence
+    }
+}
+/// True when a word closes a sentence, trailing quotes and brackets are ignored
+fn ends_sentence(word: &str) -> bool {
+    word.trim_end_matches(['"', '\'', ')'])
+        .ends_with(['.', '?', '!'])
+}
+/// Directory read for a user supplied corpus
+pub fn corpus_dir() -> Option<PathBuf> {
+    Some(data_dir()?.join("corpus"))
+}
+/// Joins every `.txt` file in the corpus directory
+fn local_corpus() -> Option<String> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(corpus_dir()?)
+        .ok()?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| is_corpus_file(p))
+        .collect();
+    paths.sort();
+    let text: Vec<String> = paths
+        .iter()
+        .filter_map(|p| fs::read_to_string(p).ok())
+        .collect();
+    (!text.is_empty()).then(|| text.join("\n"))
+}
+/// Plain text files small enough to train on quickly
+fn is_corpus_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "txt")
+        && fs::metadata(path).is_ok_and(|m| m.is_file() && m.len() <= MAX_CORPUS_FILE_BYTES)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    const TINY_CORPUS: &str = "The cat sat on the mat. A cat ran home! Did the dog see the cat? \
+        The dog sat on the step.";
+
+    /// Splits generated words back into sentences
+    fn sentences(words: &[String]) -> Vec<Vec<String>> {
+        let mut sentences = vec![vec![]];
+        for word in words {
+            sentences.last_mut().unwrap().push(word.clone());
+            if ends_sentence(word) {
+                sentences.push(vec![]);
+            }
+        }
+        sentences.retain(|s| !s.is_empty());
+        sentences
+    }
+
+    #[test]
+    fn train_starts_sentences_after_stops() {
+        let model = MarkovModel::train(TINY_CORPUS);
+        let starts: Vec<(&str, &str)> = model
+            .starts
+            .iter()
+            .map(|(a, b)| (a.as_str(), b.as_str()))
+            .collect();
+        assert_eq!(
+            starts,
+            vec![("The", "cat"), ("A", "cat"), ("Did", "the"), ("The", "dog")]
+        );
+        assert_eq!(model.singles["cat"], vec!["sat", "ran"]);
+    }
+
+    #[test]
+    fn generated_sentences_are_whole() {
+        let model = MarkovModel::train(TINY_CORPUS);
+        let mut rng = StdRng::seed_from_u64(5);
+        let words = model.generate(100, &mut rng);
+        assert!(words.len() >= 100);
+        for sentence in sentences(&words) {
+            assert!(sentence[0].starts_with(|c: char| c.is_uppercase()));
+            assert!(ends_sentence(&sentence[sentence.len() - 1]));
+        }
+    }
+
+    #[test]
+    fn backoff_mixes_sentences() {
+        // Each pair has one follower, only the single word fallback can join the two sentences
+        let model = MarkovModel::train("The cat sat. A cat ran.");
+        let mut rng = StdRng::seed_from_u64(6);
+        let words = model.generate(400, &mut rng);
+        let mixed = sentences(&words)
+            .into_iter()
+            .any(|s| s == ["The", "cat", "ran."] || s == ["A", "cat", "sat."]);
+        assert!(mixed);
+    }
+
+    #[test]
+    fn endless_sentences_are_cut_off() {
+        let model = MarkovModel::train("Go on and on and on and on and on");
+        let mut rng = StdRng::seed_from_u64(7);
+        let sentence = model.sentence(&mut rng);
+        assert_eq!(sentence.len(), MAX_SENTENCE_WORDS);
+        assert!(sentence[MAX_SENTENCE_WORDS - 1].ends_with('.'));
+    }
+
+    #[test]
+    fn dead_ends_close_the_sentence() {
+        let model = MarkovModel::train("Hello there friend");
+        let mut rng = StdRng::seed_from_u64(8);
+        assert_eq!(model.sentence(&mut rng), vec!["Hello", "there", "friend."]);
+        assert!(MarkovModel::default().generate(10, &mut rng).is_empty());
+    }
+}
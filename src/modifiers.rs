@@ -0,0 +1,217 @@
+use rand::Rng;
+
+use crate::ConfigIndex;
+
+/// Chance a word is swapped for a number when numbers are on
+const NUMBER_CHANCE: f64 = 0.1;
+/// Chance a word is capitalised with random caps
+const RANDOM_CAPS_CHANCE: f64 = 0.3;
+/// Chance a word ends a sentence with sentence case but no punctuation
+const SENTENCE_END_CHANCE: f64 = 0.1;
+
+/// Capitalisation applied to generated words
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Capitalization {
+    #[default]
+    Off,
+    Sentence,
+    Random,
+}
+/// Post processing applied on top of any text theme
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TextModifiers {
+    pub punctuation: bool,
+    pub numbers: bool,
+    pub capitalization: Capitalization,
+}
+impl TextModifiers {
+    /// Reads modifier toggles from the user config
+    pub fn from_config(config: &ConfigIndex) -> TextModifiers {
+        TextModifiers {
+            punctuation: config.punctuation_index == 1,
+            numbers: config.numbers_index == 1,
+            capitalization: match config.capitalization_index {
+                1 => Capitalization::Sentence,
+                2 => Capitalization::Random,
+                _ => Capitalization::Off,
+            },
+        }
+    }
+    /// Short tags stored with leaderboard entries, empty when plain
+    pub fn tags(&self) -> Vec<&'static str> {
+        let mut tags = Vec::new();
+        if self.punctuation {
+            tags.push("punct");
+        }
+        if self.numbers {
+            tags.push("num");
+        }
+        match self.capitalization {
+            Capitalization::Off => {}
+            Capitalization::Sentence => tags.push("sentence"),
+            Capitalization::Random => tags.push("caps"),
+        }
+        tags
+    }
+    /// Applies the modifiers to freshly generated words
+    ///
+    /// `previous` is the last word already on screen so sentence case carries over between chunks
+    pub fn apply<R: Rng>(
+        &self,
+        words: Vec<String>,
+        previous: Option<&str>,
+        rng: &mut R,
+    ) -> Vec<String> {
+        let mut sentence_start = previous.is_none_or(ends_sentence);
+        words
+            .into_iter()
+            .map(|word| {
+                let mut word = if self.numbers && rng.random_bool(NUMBER_CHANCE) {
+                    rng.random_range(0..10000).to_string()
+                } else {
+                    word
+                };
+                let capitalize = match self.capitalization {
+                    Capitalization::Off => false,
+                    Capitalization::Sentence => sentence_start,
+                    Capitalization::Random => rng.random_bool(RANDOM_CAPS_CHANCE),
+                };
+                if capitalize {
+                    word = capitalize_first(&word);
+                }
+                if self.punctuation {
+                    word = punctuate(word, rng);
+                } else if self.capitalization == Capitalization::Sentence
+                    && rng.random_bool(SENTENCE_END_CHANCE)
+                {
+                    // Full stops are still needed to start new sentences
+                    word.push('.');
+                }
+                sentence_start = ends_sentence(&word);
+                word
+            })
+            .collect()
+    }
+}
+/// True when the next word should start a new sentence
+fn ends_sentence(word: &str) -> bool {
+    word.ends_with(['.', '?', '!'])
+}
+/// Uppercases the first character of a word
+fn capitalize_first(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+/// Randomly attaches punctuation, quotes or brackets to a word
+fn punctuate<R: Rng>(word: String, rng: &mut R) -> String {
+    match rng.random_range(0..100) {
+        0..8 => format!("{},", word),
+        8..14 => format!("{}.", word),
+        14..16 => format!("{}?", word),
+        16..18 => format!("{}!", word),
+        18..20 => format!("{};", word),
+        20..22 => format!("{}:", word),
+        22..25 => format!("\"{}\"", word),
+        25..27 => format!("({})", word),
+        27..28 => format!("[{}]", word),
+        _ => word,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    fn words(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("word{}", i)).collect()
+    }
+
+    fn sentence_case(punctuation: bool) -> TextModifiers {
+        TextModifiers {
+            punctuation,
+            numbers: false,
+            capitalization: Capitalization::Sentence,
+        }
+    }
+
+    /// Every word after a full stop, question or exclamation mark is capitalised, and only those
+    fn assert_sentence_case(words: &[String], previous: Option<&str>) {
+        let mut sentence_start = previous.is_none_or(ends_sentence);
+        for word in words {
+            let first = word
+                .trim_start_matches(['"', '(', '['])
+                .chars()
+                .next()
+                .unwrap();
+            assert_eq!(first.is_uppercase(), sentence_start, "{:?}", words);
+            sentence_start = ends_sentence(word);
+        }
+    }
+
+    #[test]
+    fn sentence_case_follows_punctuation() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let out = sentence_case(true).apply(words(200), None, &mut rng);
+        assert!(out.iter().any(|w| ends_sentence(w)));
+        assert_sentence_case(&out, None);
+    }
+
+    #[test]
+    fn sentence_case_adds_full_stops_without_punctuation() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let out = sentence_case(false).apply(words(200), None, &mut rng);
+        assert!(out.iter().filter(|w| w.ends_with('.')).count() > 1);
+        assert!(
+            out.iter()
+                .all(|w| w.ends_with('.') || !w.ends_with(['?', '!']))
+        );
+        assert_sentence_case(&out, None);
+    }
+
+    #[test]
+    fn sentence_case_carries_over_between_chunks() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let modifiers = sentence_case(false);
+        let mid_sentence = modifiers.apply(words(5), Some("word"), &mut rng);
+        assert!(!mid_sentence[0].starts_with('W'));
+        let new_sentence = modifiers.apply(words(5), Some("end."), &mut rng);
+        assert!(new_sentence[0].starts_with('W'));
+    }
+
+    #[test]
+    fn punctuate_keeps_the_word() {
+        let mut rng = StdRng::seed_from_u64(4);
+        for _ in 0..200 {
+            let word = punctuate("word".to_string(), &mut rng);
+            assert!(word.contains("word"));
+            assert!(word.len() <= "word".len() + 2);
+        }
+    }
+
+    #[test]
+    fn tags_are_distinct() {
+        let mut seen = std::collections::HashSet::new();
+        for punctuation in [false, true] {
+            for numbers in [false, true] {
+                for capitalization in [
+                    Capitalization::Off,
+                    Capitalization::Sentence,
+                    Capitalization::Random,
+                ] {
+                    let tags = TextModifiers {
+                        punctuation,
+                        numbers,
+                        capitalization,
+                    }
+                    .tags();
+                    assert!(seen.insert(tags.join("+")));
+                }
+            }
+        }
+        assert!(seen.contains(""));
+    }
+}
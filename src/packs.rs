@@ -0,0 +1,211 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{RECENT_WORD_WINDOW, data_dir};
+
+/// Pack needs more unique words than the no-repeat window or generation never finishes
+pub const MIN_UNIQUE_WORDS: usize = RECENT_WORD_WINDOW + 1;
+/// Packs shipped with the app, listed before packs from the data directory
+const BUNDLED_PACKS: &[(&str, &str)] = &[
+    ("german", include_str!("data/packs/german.txt")),
+    ("french", include_str!("data/packs/french.txt")),
+    ("spanish", include_str!("data/packs/spanish.txt")),
+    ("portuguese", include_str!("data/packs/portuguese.txt")),
+];
+
+/// User supplied word list loaded from the packs directory
+#[derive(Debug, Clone)]
+pub struct WordPack {
+    pub name: String,
+    pub language: String,
+    pub description: String,
+    pub words: Vec<String>,
+}
+impl WordPack {
+    /// Parses and validates pack file contents
+    ///
+    /// Format is one word per line with optional `# key: value` header lines
+    /// for `name`, `language` and `description`, any other `#` line is a comment
+    pub fn parse(default_name: &str, contents: &str) -> Result<WordPack, String> {
+        let mut name = default_name.to_string();
+        let mut language = "Unknown".to_string();
+        let mut description = String::new();
+        let mut words = Vec::new();
+        let mut seen: HashMap<String, usize> = HashMap::new();
+
+        for (i, line) in contents.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            // Header and comment lines
+            if let Some(comment) = line.strip_prefix('#') {
+                if let Some((key, value)) = comment.split_once(':') {
+                    let value = value.trim().to_string();
+                    match key.trim().to_lowercase().as_str() {
+                        "name" if !value.is_empty() => name = value,
+                        "language" if !value.is_empty() => language = value,
+                        "description" => description = value,
+                        _ => {}
+                    }
+                }
+                continue;
+            }
+            // Words are typed one at a time so they cannot contain spaces
+            if line.split_whitespace().count() > 1 {
+                return Err(format!(
+                    "line {}: entry '{}' contains whitespace",
+                    line_number, line
+                ));
+            }
+            let word = line.to_string();
+            if let Some(first_line) = seen.get(&word) {
+                return Err(format!(
+                    "line {}: duplicate word '{}' (first seen on line {})",
+                    line_number, word, first_line
+                ));
+            }
+            seen.insert(word.clone(), line_number);
+            words.push(word);
+        }
+
+        if words.is_empty() {
+            return Err("pack contains no words".to_string());
+        }
+        if words.len() < MIN_UNIQUE_WORDS {
+            return Err(format!(
+                "only {} unique words, at least {} are needed",
+                words.len(),
+                MIN_UNIQUE_WORDS
+            ));
+        }
+        Ok(WordPack {
+            name,
+            language,
+            description,
+            words,
+        })
+    }
+    /// Reads a single pack file from disk
+    fn from_file(path: &Path) -> Result<WordPack, String> {
+        let default_name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        WordPack::parse(&default_name, &contents)
+    }
+}
+/// Directory scanned for `.txt` word packs
+pub fn packs_dir() -> Option<PathBuf> {
+    Some(data_dir()?.join("packs"))
+}
+/// Loads bundled and user packs, returning readable errors for any that were skipped
+pub fn load_word_packs() -> (Vec<WordPack>, Vec<String>) {
+    let mut packs: Vec<WordPack> = BUNDLED_PACKS
+        .iter()
+        .filter_map(|(name, contents)| WordPack::parse(name, contents).ok())
+        .collect();
+    let mut errors = Vec::new();
+    let Some(dir) = packs_dir() else {
+        return (packs, errors);
+    };
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return (packs, errors);
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        let file_name = path
+            .file_name()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        match WordPack::from_file(&path) {
+            Ok(pack) => {
+                if packs.iter().any(|p: &WordPack| p.name == pack.name) {
+                    errors.push(format!(
+                        "{}: a pack named '{}' is already loaded",
+                        file_name, pack.name
+                    ));
+                } else {
+                    packs.push(pack);
+                }
+            }
+            Err(e) => errors.push(format!("{}: {}", file_name, e)),
+        }
+    }
+    (packs, errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pack body with enough distinct words to pass validation
+    fn words(count: usize) -> String {
+        (0..count).map(|i| format!("word{}\n", i)).collect()
+    }
+
+    #[test]
+    fn parse_reads_headers_and_words() {
+        let contents = format!(
+            "# name: Birds\n# language: English\n# description: garden birds\n# a comment\n\n{}",
+            words(MIN_UNIQUE_WORDS)
+        );
+        let pack = WordPack::parse("birds_file", &contents).unwrap();
+        assert_eq!(pack.name, "Birds");
+        assert_eq!(pack.language, "English");
+        assert_eq!(pack.description, "garden birds");
+        assert_eq!(pack.words.len(), MIN_UNIQUE_WORDS);
+        assert_eq!(pack.words[0], "word0");
+    }
+
+    #[test]
+    fn parse_falls_back_to_file_name() {
+        let pack = WordPack::parse("birds", &words(MIN_UNIQUE_WORDS)).unwrap();
+        assert_eq!(pack.name, "birds");
+        assert_eq!(pack.language, "Unknown");
+    }
+
+    #[test]
+    fn parse_rejects_bad_packs() {
+        assert_eq!(
+            WordPack::parse("p", "# name: empty\n").unwrap_err(),
+            "pack contains no words"
+        );
+        assert!(
+            WordPack::parse("p", &words(MIN_UNIQUE_WORDS - 1))
+                .unwrap_err()
+                .starts_with("only")
+        );
+        let spaced = format!("{}two words\n", words(MIN_UNIQUE_WORDS));
+        assert!(
+            WordPack::parse("p", &spaced)
+                .unwrap_err()
+                .contains("contains whitespace")
+        );
+        let duplicate = format!("{}word3\n", words(MIN_UNIQUE_WORDS));
+        assert!(
+            WordPack::parse("p", &duplicate)
+                .unwrap_err()
+                .contains("duplicate word 'word3' (first seen on line 4)")
+        );
+    }
+
+    #[test]
+    fn bundled_packs_parse() {
+        for (name, contents) in BUNDLED_PACKS {
+            if let Err(e) = WordPack::parse(name, contents) {
+                panic!("bundled pack {}: {}", name, e);
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use rand::{Rng, seq::SliceRandom};
+
+/// Word count boundaries for quote length classes
+const SHORT_MAX_WORDS: usize = 20;
+const MEDIUM_MAX_WORDS: usize = 45;
+
+/// Length class used to filter quotes
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum QuoteLength {
+    #[default]
+    Any,
+    Short,
+    Medium,
+    Long,
+}
+impl QuoteLength {
+    /// Maps config index to length class
+    pub fn from_index(index: usize) -> QuoteLength {
+        match index {
+            1 => QuoteLength::Short,
+            2 => QuoteLength::Medium,
+            3 => QuoteLength::Long,
+            _ => QuoteLength::Any,
+        }
+    }
+}
+/// Single passage from the bundled corpus
+#[derive(Debug)]
+pub struct Quote {
+    pub text: &'static str,
+    pub source: &'static str,
+}
+impl Quote {
+    /// Words as typed, punctuation stays attached to its word
+    pub fn words(&self) -> Vec<String> {
+        self.text.split_whitespace().map(String::from).collect()
+    }
+    /// Length class derived from word count
+    pub fn length(&self) -> QuoteLength {
+        match self.text.split_whitespace().count() {
+            n if n <= SHORT_MAX_WORDS => QuoteLength::Short,
+            n if n <= MEDIUM_MAX_WORDS => QuoteLength::Medium,
+            _ => QuoteLength::Long,
+        }
+    }
+}
+/// Picks random passages of the chosen length until there are at least `min_words` words
+pub fn pick_passages<R: Rng>(
+    length: QuoteLength,
+    min_words: usize,
+    rng: &mut R,
+) -> Vec<&'static Quote> {
+    let mut candidates: Vec<&'static Quote> = QUOTES
+        .iter()
+        .filter(|q| length == QuoteLength::Any || q.length() == length)
+        .collect();
+    candidates.shuffle(rng);
+    let mut passages = Vec::new();
+    let mut word_count = 0;
+    for quote in candidates {
+        if word_count >= min_words {
+            break;
+        }
+        word_count += quote.text.split_whitespace().count();
+        passages.push(quote);
+    }
+    passages
+}
+/// Bundled quote corpus
+pub const QUOTES: &[Quote] = &[
+    Quote {
+        text: "It was the best of times, it was the worst of times, it was the age of wisdom, it was the age of foolishness, it was the epoch of belief, it was the epoch of incredulity.",
+        source: "Charles Dickens, A Tale of Two Cities",
+    },
+    Quote {
+        text: "It is a truth universally acknowledged, that a single man in possession of a good fortune, must be in want of a wife.",
+        source: "Jane Austen, Pride and Prejudice",
+    },
+    Quote {
+        text: "Call me Ishmael. Some years ago, never mind how long precisely, having little or no money in my purse, and nothing particular to interest me on shore, I thought I would sail about a little and see the watery part of the world.",
+        source: "Herman Melville, Moby-Dick",
+    },
+    Quote {
+        text: "Happy families are all alike; every unhappy family is unhappy in its own way.",
+        source: "Leo Tolstoy, Anna Karenina",
+    },
+    Quote {
+        text: "Alice was beginning to get very tired of sitting by her sister on the bank, and of having nothing to do: once or twice she had peeped into the book her sister was reading, but it had no pictures or conversations in it, \"and what is the use of a book,\" thought Alice, \"without pictures or conversations?\"",
+        source: "Lewis Carroll, Alice's Adventures in Wonderland",
+    },
+    Quote {
+        text: "Whether I shall turn out to be the hero of my own life, or whether that station will be held by anybody else, these pages must show.",
+        source: "Charles Dickens, David Copperfield",
+    },
+    Quote {
+        text: "The only thing we have to fear is fear itself.",
+        source: "Franklin D. Roosevelt, First Inaugural Address",
+    },
+    Quote {
+        text: "Four score and seven years ago our fathers brought forth on this continent, a new nation, conceived in Liberty, and dedicated to the proposition that all men are created equal.",
+        source: "Abraham Lincoln, Gettysburg Address",
+    },
+    Quote {
+        text: "I went to the woods because I wished to live deliberately, to front only the essential facts of life, and see if I could not learn what it had to teach, and not, when I came to die, discover that I had not lived.",
+        source: "Henry David Thoreau, Walden",
+    },
+    Quote {
+        text: "To be, or not to be, that is the question.",
+        source: "William Shakespeare, Hamlet",
+    },
+    Quote {
+        text: "All the world's a stage, and all the men and women merely players; they have their exits and their entrances, and one man in his time plays many parts.",
+        source: "William Shakespeare, As You Like It",
+    },
+    Quote {
+        text: "There is nothing either good or bad, but thinking makes it so.",
+        source: "William Shakespeare, Hamlet",
+    },
+    Quote {
+        text: "The fault, dear Brutus, is not in our stars, but in ourselves, that we are underlings.",
+        source: "William Shakespeare, Julius Caesar",
+    },
+    Quote {
+        text: "Two roads diverged in a wood, and I, I took the one less traveled by, and that has made all the difference.",
+        source: "Robert Frost, The Road Not Taken",
+    },
+    Quote {
+        text: "Hope is the thing with feathers that perches in the soul, and sings the tune without the words, and never stops at all.",
+        source: "Emily Dickinson, Hope is the thing with feathers",
+    },
+    Quote {
+        text: "It is not the critic who counts; not the man who points out how the strong man stumbles, or where the doer of deeds could have done them better. The credit belongs to the man who is actually in the arena.",
+        source: "Theodore Roosevelt, Citizenship in a Republic",
+    },
+    Quote {
+        text: "We hold these truths to be self-evident, that all men are created equal, that they are endowed by their Creator with certain unalienable Rights, that among these are Life, Liberty and the pursuit of Happiness.",
+        source: "United States Declaration of Independence",
+    },
+    Quote {
+        text: "In the beginning of a change the patriot is a scarce man, and brave, and hated and scorned. When his cause succeeds, the timid join him, for then it costs nothing to be a patriot.",
+        source: "Mark Twain, Notebook",
+    },
+    Quote {
+        text: "The secret of getting ahead is getting started.",
+        source: "Mark Twain",
+    },
+    Quote {
+        text: "Whenever you find yourself on the side of the majority, it is time to pause and reflect.",
+        source: "Mark Twain, Notebook",
+    },
+    Quote {
+        text: "Tom! No answer. Tom! No answer. What's gone with that boy, I wonder? You TOM! No answer.",
+        source: "Mark Twain, The Adventures of Tom Sawyer",
+    },
+    Quote {
+        text: "Reader, I married him.",
+        source: "Charlotte Bronte, Jane Eyre",
+    },
+    Quote {
+        text: "I am no bird; and no net ensnares me: I am a free human being with an independent will, which I now exert to leave you.",
+        source: "Charlotte Bronte, Jane Eyre",
+    },
+    Quote {
+        text: "Whatever our souls are made of, his and mine are the same.",
+        source: "Emily Bronte, Wuthering Heights",
+    },
+    Quote {
+        text: "The man who does not read has no advantage over the man who cannot read.",
+        source: "Attributed to Mark Twain",
+    },
+    Quote {
+        text: "It is a far, far better thing that I do, than I have ever done; it is a far, far better rest that I go to than I have ever known.",
+        source: "Charles Dickens, A Tale of Two Cities",
+    },
+    Quote {
+        text: "Marley was dead: to begin with. There is no doubt whatever about that. The register of his burial was signed by the clergyman, the clerk, the undertaker, and the chief mourner. Scrooge signed it: and Scrooge's name was good upon 'Change, for anything he chose to put his hand to.",
+        source: "Charles Dickens, A Christmas Carol",
+    },
+    Quote {
+        text: "Programs must be written for people to read, and only incidentally for machines to execute.",
+        source: "Harold Abelson and Gerald Jay Sussman, SICP",
+    },
+    Quote {
+        text: "Simple things should be simple, complex things should be possible.",
+        source: "Alan Kay",
+    },
+    Quote {
+        text: "The quick brown fox jumps over the lazy dog; pack my box with five dozen liquor jugs! How vexingly quick daft zebras jump, and how razorback-jumping frogs can level six piqued gymnasts.",
+        source: "Pangrams",
+    },
+    Quote {
+        text: "Once upon a midnight dreary, while I pondered, weak and weary, over many a quaint and curious volume of forgotten lore, while I nodded, nearly napping, suddenly there came a tapping, as of some one gently rapping, rapping at my chamber door.",
+        source: "Edgar Allan Poe, The Raven",
+    },
+    Quote {
+        text: "I have not failed. I've just found 10,000 ways that won't work.",
+        source: "Attributed to Thomas Edison",
+    },
+];
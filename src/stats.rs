@@ -0,0 +1,160 @@
+use std::{
+    ops::{AddAssign, SubAssign},
+    time::Duration,
+};
+
+/// Characters that make up one word for WPM
+pub const CHARS_PER_WORD: f64 = 5.0;
+
+/// Character totals every WPM figure is worked out from
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CharStats {
+    pub typed: usize,              // typed characters, word gaps not included
+    pub correct: usize,            // typed characters matching the target
+    pub spaces: usize,             // gaps typed after finished words
+    pub correct_spaces: usize,     // gaps typed where the target word ends
+    pub correct_word_chars: usize, // characters and gaps of words typed exactly
+}
+/// Speed of a round in the three ways it is reported
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Wpm {
+    pub raw: f64,      // everything typed
+    pub net: f64,      // correct characters and spaces, the headline figure
+    pub adjusted: f64, // only words typed exactly
+}
+impl CharStats {
+    /// Totals for one word, `finished` when it was ended with a space or enter
+    pub fn word(typed: &[&str], target: &[&str], finished: bool) -> CharStats {
+        let correct = typed.iter().zip(target).filter(|(a, b)| a == b).count();
+        let spaces = usize::from(finished);
+        CharStats {
+            typed: typed.len(),
+            correct,
+            spaces,
+            correct_spaces: usize::from(finished && typed.len() == target.len()),
+            correct_word_chars: match typed == target {
+                true => typed.len() + spaces,
+                false => 0,
+            },
+        }
+    }
+    /// Wrong characters typed
+    pub fn errors(&self) -> usize {
+        self.typed - self.correct
+    }
+    /// Character accuracy as a percentage
+    pub fn accuracy(&self) -> f64 {
+        match self.typed {
+            0 => 0.0,
+            typed => self.correct as f64 / typed as f64 * 100_f64,
+        }
+    }
+    /// Raw, net and adjusted WPM over the measured time
+    pub fn wpm(&self, elapsed: Duration) -> Wpm {
+        Wpm {
+            raw: chars_wpm(self.typed + self.spaces, elapsed),
+            net: chars_wpm(self.correct + self.correct_spaces, elapsed),
+            adjusted: chars_wpm(self.correct_word_chars, elapsed),
+        }
+    }
+}
+impl AddAssign for CharStats {
+    fn add_assign(&mut self, other: CharStats) {
+        self.typed += other.typed;
+        self.correct += other.correct;
+        self.spaces += other.spaces;
+        self.correct_spaces += other.correct_spaces;
+        self.correct_word_chars += other.correct_word_chars;
+    }
+}
+impl SubAssign for CharStats {
+    fn sub_assign(&mut self, other: CharStats) {
+        self.typed = self.typed.saturating_sub(other.typed);
+        self.correct = self.correct.saturating_sub(other.correct);
+        self.spaces = self.spaces.saturating_sub(other.spaces);
+        self.correct_spaces = self.correct_spaces.saturating_sub(other.correct_spaces);
+        self.correct_word_chars = self
+            .correct_word_chars
+            .saturating_sub(other.correct_word_chars);
+    }
+}
+/// Totals taken every second of a round for the end screen chart
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Sample {
+    pub seconds: f64,
+    pub chars: CharStats,
+    pub mistakes: usize, // wrong keys pressed so far
+}
+/// Time spent typing, paused time is left out
+pub fn active_time(total: Duration, paused: Duration) -> Duration {
+    total.saturating_sub(paused)
+}
+/// Characters per five, per minute, rounds shorter than a second count as one second
+pub fn chars_wpm(chars: usize, elapsed: Duration) -> f64 {
+    chars as f64 / CHARS_PER_WORD / (elapsed.as_secs_f64().max(1.0) / 60.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_round_has_no_speed() {
+        let chars = CharStats::default();
+        let wpm = chars.wpm(Duration::ZERO);
+        assert_eq!((wpm.raw, wpm.net, wpm.adjusted), (0.0, 0.0, 0.0));
+        assert_eq!(chars.accuracy(), 0.0);
+        assert_eq!(chars.errors(), 0);
+    }
+
+    #[test]
+    fn finished_word_counts_its_space() {
+        let chars = CharStats::word(&["c", "a", "t"], &["c", "a", "t"], true);
+        assert_eq!(chars.typed, 3);
+        assert_eq!(chars.correct_spaces, 1);
+        assert_eq!(chars.correct_word_chars, 4);
+        // 4 characters in 12 seconds is 0.8 words in a fifth of a minute
+        assert_eq!(chars.wpm(Duration::from_secs(12)).net, 4.0);
+    }
+
+    #[test]
+    fn partial_final_word_counts_correct_characters_only() {
+        let chars = CharStats::word(&["h", "o"], &["h", "o", "u", "s", "e"], false);
+        assert_eq!(chars.correct, 2);
+        assert_eq!(chars.spaces, 0);
+        assert_eq!(chars.correct_word_chars, 0);
+        let wpm = chars.wpm(Duration::from_secs(6));
+        assert_eq!(wpm.net, 4.0);
+        assert_eq!(wpm.adjusted, 0.0);
+    }
+
+    #[test]
+    fn wrong_and_short_words_lose_their_space() {
+        let mut chars = CharStats::word(&["c", "u", "t"], &["c", "a", "t"], true);
+        chars += CharStats::word(&["d", "o"], &["d", "o", "g"], true);
+        assert_eq!(chars.errors(), 1);
+        assert_eq!(chars.spaces, 2);
+        assert_eq!(chars.correct_spaces, 1);
+        assert_eq!(chars.correct_word_chars, 0);
+        chars -= CharStats::word(&["d", "o"], &["d", "o", "g"], true);
+        assert_eq!(chars.typed, 3);
+        assert_eq!(chars.correct_spaces, 1);
+    }
+
+    #[test]
+    fn short_rounds_count_as_one_second() {
+        assert_eq!(chars_wpm(5, Duration::from_millis(200)), 60.0);
+        assert_eq!(chars_wpm(5, Duration::from_secs(1)), 60.0);
+    }
+
+    #[test]
+    fn paused_time_is_excluded() {
+        let active = active_time(Duration::from_secs(90), Duration::from_secs(30));
+        assert_eq!(active, Duration::from_secs(60));
+        assert_eq!(chars_wpm(250, active), 50.0);
+        assert_eq!(
+            active_time(Duration::from_secs(5), Duration::from_secs(10)),
+            Duration::ZERO
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use std::time::{Duration, Instant};
+
+/// Lives at the start of a survival round
+pub const SURVIVAL_LIVES: usize = 3;
+/// Words to clear before the next level
+pub const WORDS_PER_LEVEL: usize = 10;
+/// Time for each word on the first level
+const START_BUDGET_MS: u64 = 4000;
+/// Time taken off each word per level
+const BUDGET_STEP_MS: u64 = 300;
+/// Word timer never drops below this
+const MIN_BUDGET_MS: u64 = 1000;
+
+/// Lives and word timer for a survival round
+#[derive(Debug)]
+pub struct Survival {
+    pub lives: usize,
+    pub word_start: Option<Instant>, // None until the first key of the round
+    pub timed_out: Vec<usize>,       // words skipped by the timer, no space was typed after them
+}
+impl Default for Survival {
+    fn default() -> Survival {
+        Survival {
+            lives: SURVIVAL_LIVES,
+            word_start: None,
+            timed_out: vec![],
+        }
+    }
+}
+impl Survival {
+    /// Level reached after a number of words, starting at 1
+    pub fn level(words: usize) -> usize {
+        words / WORDS_PER_LEVEL + 1
+    }
+    /// Time allowed for each word on a level
+    pub fn budget(level: usize) -> Duration {
+        let step = BUDGET_STEP_MS * (level as u64 - 1);
+        Duration::from_millis(START_BUDGET_MS.saturating_sub(step).max(MIN_BUDGET_MS))
+    }
+    /// Time left on the current word not counting a pause in progress, None before the round starts
+    pub fn time_left(&self, level: usize, paused: Duration) -> Option<Duration> {
+        let start = self.word_start?;
+        Some(Survival::budget(level).saturating_sub(start.elapsed().saturating_sub(paused)))
+    }
+    /// Starts the timer for the next word
+    pub fn next_word(&mut self) {
+        self.word_start = Some(Instant::now());
+    }
+    /// Takes a life, true when none are left
+    pub fn lose_life(&mut self) -> bool {
+        self.lives = self.lives.saturating_sub(1);
+        self.lives == 0
+    }
+}
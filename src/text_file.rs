@@ -0,0 +1,40 @@
+use std::{fs, io, path::Path};
+
+/// Words loaded onto the screen when a round starts
+pub const FIRST_CHUNK_WORDS: usize = 60;
+/// Words added each time the typist nears the end of the loaded text
+pub const NEXT_CHUNK_WORDS: usize = 30;
+
+/// Text supplied with `--text`, typed in order instead of generated words
+#[derive(Debug, Clone)]
+pub struct TextFile {
+    pub name: String,
+    pub words: Vec<String>,
+}
+impl TextFile {
+    /// Reads a file, or stdin when the path is `-`
+    pub fn load(path: &str) -> Result<TextFile, String> {
+        let (name, contents) = if path == "-" {
+            let contents = io::read_to_string(io::stdin())
+                .map_err(|e| format!("failed to read stdin: {}", e))?;
+            ("stdin".to_string(), contents)
+        } else {
+            let contents =
+                fs::read_to_string(path).map_err(|e| format!("failed to read {}: {}", path, e))?;
+            let name = Path::new(path)
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.to_string());
+            (name, contents)
+        };
+        let words: Vec<String> = contents.split_whitespace().map(String::from).collect();
+        if words.is_empty() {
+            return Err(format!("{} contains no words to type", name));
+        }
+        Ok(TextFile { name, words })
+    }
+    /// Next chunk of words after `start`, empty once the file is exhausted
+    pub fn chunk(&self, start: usize, count: usize) -> Vec<String> {
+        self.words.iter().skip(start).take(count).cloned().collect()
+    }
+}